@@ -7,19 +7,35 @@ a Rust + PyO3 based asynchronous Python SDK that fully encapsulates the
 
 from __future__ import annotations
 
-from typing import List, Literal, Sequence
+from typing import (
+    Any,
+    AsyncIterable,
+    Awaitable,
+    Callable,
+    ClassVar,
+    Dict,
+    Iterator,
+    List,
+    Literal,
+    Mapping,
+    Sequence,
+)
 from typing import TypedDict
 
 
 # Type aliases for API parameters
 ModelType = Literal["speed", "quality", "quality_v2"]
-"""Transcription model type: speed, quality, or quality_v2."""
+"""Transcription model type: speed, quality, or quality_v2.
+
+The model and export arguments also accept other spellings of these names at
+runtime, such as ``"QualityV2"``, ``"QUALITY_V2"`` or ``"quality-v2"``.
+"""
 
 ExportTypeLiteral = Literal["transcript", "overview", "summary"]
 """Export type: transcript, overview, or summary."""
 
-ExportFormatLiteral = Literal["pdf", "txt", "docx"]
-"""Export format: pdf, txt, or docx."""
+ExportFormatLiteral = Literal["pdf", "txt", "docx", "srt", "vtt"]
+"""Export format: pdf, txt, docx, srt, or vtt (srt and vtt are rendered client-side, transcripts only)."""
 
 LanguageCode = Literal["zh", "en", "ja", "ko", "fr", "de"]
 """Language code for translation: zh (Chinese), en (English), ja (Japanese), ko (Korean), fr (French), or de (German)."""
@@ -33,6 +49,8 @@ class SessionCreateResult:
     usage_id: str
     max_time: int
 
+    def to_dict(self) -> Dict[str, Any]: ...
+
 
 class _SessionCloseRequired(TypedDict):
     status: str
@@ -46,6 +64,8 @@ class SessionCloseResult:
     error_code: int | None
     message: str | None
 
+    def to_dict(self) -> Dict[str, Any]: ...
+
 
 class UploadResult:
     """Upload response for either normal or one-sentence mode.
@@ -56,6 +76,9 @@ class UploadResult:
         status: Present when ``kind == "one_sentence"``.
         message: Optional status message.
         data: Transcription result when ``kind == "one_sentence"``.
+        model: Model the upload was accepted with, in the lowercase
+            spelling ``model`` arguments take. Set for both kinds; it differs
+            from the requested one when ``fallback_model`` was used.
     """
 
     kind: Literal["normal", "one_sentence"]
@@ -63,33 +86,101 @@ class UploadResult:
     status: str | None
     message: str | None
     data: str | None
+    model: ModelType | None
+
+    def to_dict(self) -> Dict[str, Any]: ...
+
+
+class UploadOutcome:
+    """Outcome of one file in ``transcribe_upload_many``.
+
+    Attributes:
+        filepath: The path as it was passed in.
+        result: Upload result when the file was uploaded.
+        error: Error message when it was not, e.g. a missing file or a
+            rejected request.
+        ok: True when there is a result.
+    """
+
+    filepath: str
+    result: UploadResult | None
+    error: str | None
+
+    @property
+    def ok(self) -> bool: ...
+
+    def to_dict(self) -> Dict[str, Any]: ...
 
 
 class UtterancePayload:
-    """Utterance information for transcription or translation."""
+    """Utterance information for transcription or translation.
+
+    All fields are writable, so transcripts can be corrected in place and
+    passed back to ``transcribe_create_summary`` or ``translate_utterances``.
+    ``str()`` gives a transcript line such as ``[1.00s-2.50s] speaker 0: 你好``.
+    Instances compare by value, times as floats. Being mutable, they are not
+    hashable.
+    """
 
     start_time: float
     end_time: float
     text: str
     speaker: int
 
+    def to_dict(self) -> Dict[str, Any]: ...
+
+    @staticmethod
+    def from_dict(data: Mapping[str, Any]) -> UtterancePayload:
+        """Rebuild an instance from ``to_dict()`` output, e.g. after a JSON
+        round trip. Raises ``InvalidInputError`` naming the first missing or
+        mistyped field; optional fields may be absent and unknown keys are
+        ignored."""
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+
+    __hash__: ClassVar[None]  # type: ignore[assignment]
+
 
 class SummaryContent:
-    """Summary content returned from transcription or translation."""
+    """Summary content returned from transcription or translation.
+
+    Read-only; instances compare and hash by value.
+    """
 
     short: str
     long: str
     all: str
     keywords: List[str]
 
+    def to_dict(self) -> Dict[str, Any]: ...
+
+    def __eq__(self, other: object) -> bool: ...
+
+    def __hash__(self) -> int: ...
+
 
 class CallbackHistoryItem:
-    """Callback history item."""
+    """Callback history item.
+
+    Read-only; instances compare and hash by value.
+    """
 
     timestamp: str
     status: str
     code: int
 
+    def to_dict(self) -> Dict[str, Any]: ...
+
+    @staticmethod
+    def from_dict(data: Mapping[str, Any]) -> CallbackHistoryItem:
+        """Inverse of ``to_dict``; see ``UtterancePayload.from_dict``."""
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+
+    def __hash__(self) -> int: ...
+
 
 class CallbackRequestPayload(TypedDict, total=False):
     """Callback request payload for transcription status updates.
@@ -117,7 +208,23 @@ class _StatusResponseRequired(TypedDict):
 
 
 class StatusResponse:
-    """Response from getting transcription or summary task status."""
+    """Response from getting transcription or summary task status.
+
+    ``overview_md``, ``summary_md``, ``details`` and ``keywords`` are writable.
+    Reading ``details`` (or indexing the response) returns copies, so
+    ``status.details[0].text = "x"`` is lost. Edit a copy of the list and
+    assign it back, or use ``with_details``::
+
+        details = status.details
+        details[0].text = "x"
+        status = status.with_details(details)
+
+    ``speaker_map`` maps original to renumbered speaker ids when the status
+    was fetched with ``normalize_speakers=True``, and is None otherwise.
+
+    ``str()`` gives the transcript, one ``UtterancePayload`` line per
+    utterance, or the repr when there are no details.
+    """
 
     status: str
     overview_md: str | None
@@ -129,6 +236,66 @@ class StatusResponse:
     keywords: List[str]
     callback_history: List[CallbackHistoryItem]
     task_type: str | None
+    speaker_map: Dict[int, int] | None
+
+    def has_keyword(self, keyword: str, *, case_sensitive: bool = False) -> bool:
+        """Whether ``keyword`` is one of the task's keywords."""
+        ...
+
+    def find_utterances(
+        self, query: str, *, case_sensitive: bool = False
+    ) -> List[UtterancePayload]:
+        """Utterances whose text contains ``query``, in transcript order."""
+        ...
+
+    def total_duration(self) -> float:
+        """Seconds from the earliest start to the latest end in ``details``,
+        gaps included; 0.0 when there are no utterances."""
+        ...
+
+    def speaking_time(self) -> float:
+        """Sum of each utterance's duration, excluding gaps. Overlapping
+        utterances are each counted in full."""
+        ...
+
+    def utterances_by_speaker(self, speaker: int) -> List[UtterancePayload]:
+        """Utterances spoken by ``speaker``, in transcript order."""
+        ...
+
+    def __len__(self) -> int:
+        """Number of utterances in ``details``."""
+        ...
+
+    def __getitem__(self, index: int) -> UtterancePayload:
+        """Utterance at ``index`` in ``details``; negative indices count from
+        the end. Raises ``IndexError`` when out of range. Slices are not
+        supported; slice ``status.details`` instead."""
+        ...
+
+    def __iter__(self) -> Iterator[UtterancePayload]:
+        """Iterates over ``details``."""
+        ...
+
+    def __contains__(self, item: object) -> bool:
+        """``utterance in status``: whether an equal utterance is in
+        ``details``. Use ``has_keyword`` to look up keywords."""
+        ...
+
+    def __bool__(self) -> bool:
+        """Always true, even when ``details`` is empty."""
+        ...
+
+    def to_dict(self) -> Dict[str, Any]: ...
+
+    @staticmethod
+    def from_dict(data: Mapping[str, Any]) -> StatusResponse:
+        """Inverse of ``to_dict``; see ``UtterancePayload.from_dict``."""
+        ...
+
+    def with_details(self, details: Sequence[UtterancePayload]) -> StatusResponse:
+        """A copy of this response with ``details`` replaced; this response is
+        left unchanged."""
+        ...
 
 
 class CallbackResponse:
@@ -136,6 +303,8 @@ class CallbackResponse:
 
     status: str
 
+    def to_dict(self) -> Dict[str, Any]: ...
+
 
 class ShareLinkResponse:
     """Response from getting a share link."""
@@ -144,12 +313,49 @@ class ShareLinkResponse:
     expiration_time: int
     expired_at: str
 
+    def to_dict(self) -> Dict[str, Any]: ...
+
 
 class SummaryCreateResponse:
     """Response from creating a summary task."""
 
     task_id: str
 
+    def to_dict(self) -> Dict[str, Any]: ...
+
+
+class ChunkedSummaryResponse:
+    """Summary tasks created by ``transcribe_create_summary_chunked``.
+
+    Attributes:
+        chunks: One summary task per chunk, in transcript order.
+        combined: Summary of the chunk summaries, when requested.
+    """
+
+    chunks: List[SummaryCreateResponse]
+    combined: SummaryCreateResponse | None
+
+    def to_dict(self) -> Dict[str, Any]: ...
+
+
+class TranslatedSummaryResponse:
+    """Result of ``TranscribeApi.translate_with_summary``.
+
+    Attributes:
+        translation: Finished translation of the task.
+        summary: Status of the summary task created from the translation.
+    """
+
+    translation: TranscribeTranslationResponse
+    summary: StatusResponse
+
+    def to_dict(self) -> Dict[str, Any]: ...
+
+    @staticmethod
+    def from_dict(data: Mapping[str, Any]) -> TranslatedSummaryResponse:
+        """Inverse of ``to_dict``; see ``UtterancePayload.from_dict``."""
+        ...
+
 
 class TextTranslationResponse:
     """Response from translating text."""
@@ -157,6 +363,13 @@ class TextTranslationResponse:
     status: str
     data: str
 
+    def to_dict(self) -> Dict[str, Any]: ...
+
+    @staticmethod
+    def from_dict(data: Mapping[str, Any]) -> TextTranslationResponse:
+        """Inverse of ``to_dict``; see ``UtterancePayload.from_dict``."""
+        ...
+
 
 class UtteranceTranslationResponse:
     """Response from translating utterances."""
@@ -165,6 +378,13 @@ class UtteranceTranslationResponse:
     target_language: str
     details: List[UtterancePayload]
 
+    def to_dict(self) -> Dict[str, Any]: ...
+
+    @staticmethod
+    def from_dict(data: Mapping[str, Any]) -> UtteranceTranslationResponse:
+        """Inverse of ``to_dict``; see ``UtterancePayload.from_dict``."""
+        ...
+
 
 class TranslationDetail:
     """Translation detail combining utterance fields and translated text."""
@@ -175,6 +395,13 @@ class TranslationDetail:
     speaker: int
     translations: dict[str, str]
 
+    def to_dict(self) -> Dict[str, Any]: ...
+
+    @staticmethod
+    def from_dict(data: Mapping[str, Any]) -> TranslationDetail:
+        """Inverse of ``to_dict``; see ``UtterancePayload.from_dict``."""
+        ...
+
 
 class _TranscribeTranslationRequired(TypedDict):
     """Base required fields for transcription translation response."""
@@ -185,7 +412,12 @@ class _TranscribeTranslationRequired(TypedDict):
 
 
 class TranscribeTranslationResponse:
-    """Response from translating a transcription task."""
+    """Response from translating a transcription task.
+
+    ``str()`` gives the translated transcript in ``UtterancePayload`` line
+    form, keeping the original text of utterances without a translation, or
+    the repr when there are no details.
+    """
 
     task_id: str
     task_type: Literal["transcribe", "summary"]
@@ -197,6 +429,234 @@ class TranscribeTranslationResponse:
     summary_md: str | None
     keywords: List[str] | None
 
+    def to_dict(self) -> Dict[str, Any]: ...
+
+    @staticmethod
+    def from_dict(data: Mapping[str, Any]) -> TranscribeTranslationResponse:
+        """Inverse of ``to_dict``; see ``UtterancePayload.from_dict``."""
+        ...
+
+
+class CapturedExchange:
+    """A request/response pair recorded by a client created with ``debug_capture``.
+
+    Attributes:
+        method: Name of the ``TranscribeApi`` method that was called.
+        request: JSON-encoded request parameters, with the token redacted.
+        response: Debug rendering of the parsed response, if the call succeeded.
+        error: Error message, if the call failed.
+    """
+
+    method: str
+    request: str
+    response: str | None
+    error: str | None
+
+
+class StreamStats:
+    """Counters of a ``TranscribeStream``, as returned by ``stats()``.
+
+    Attributes:
+        frames_sent: Text and binary frames written successfully.
+        bytes_sent: Payload bytes of those frames.
+        messages_received: Messages delivered to readers.
+        bytes_received: Payload bytes of those messages.
+        reconnects: Number of reconnections performed.
+        elapsed_seconds: Seconds since ``start()`` completed (0.0 before).
+    """
+
+    frames_sent: int
+    bytes_sent: int
+    messages_received: int
+    bytes_received: int
+    reconnects: int
+    elapsed_seconds: float
+
+    def to_dict(self) -> Dict[str, Any]: ...
+
+
+class StreamMessage:
+    """A streaming message decoded by ``read_next_parsed``.
+
+    Attributes:
+        message_type: Value of the message's ``type``/``event``/``action``
+            key, or ``"unknown"`` when the frame is not a JSON object or has
+            no such key.
+        is_final: Whether the message carries a final (not partial) result.
+        utterances: Utterances found in the message, possibly empty.
+        utterance: The first of ``utterances``, or None.
+        raw: The frame exactly as received.
+    """
+
+    message_type: str
+    is_final: bool
+    utterances: List[UtterancePayload]
+    raw: str
+
+    @property
+    def utterance(self) -> UtterancePayload | None: ...
+    def to_dict(self) -> Dict[str, Any]: ...
+
+
+class ModelRecommendation:
+    """Upload settings suggested by ``recommend_model``.
+
+    Attributes:
+        model: Model to pass to ``transcribe_upload``.
+        short_asr: Whether to use the one-sentence endpoint.
+        transcribe_only: Whether to skip summary generation.
+        duration: Audio duration the recommendation is based on, in seconds.
+    """
+
+    model: ModelType
+    short_asr: bool
+    transcribe_only: bool
+    duration: float
+
+    def to_dict(self) -> Dict[str, Any]: ...
+
+
+class CoverageReport:
+    """How much of the audio a transcript covers, from ``transcript_coverage``.
+
+    Attributes:
+        covered_seconds: End of the last utterance, capped at the audio length.
+        audio_seconds: Known audio duration.
+        fraction: ``covered_seconds / audio_seconds``.
+        likely_truncated: True if ``fraction`` is below the threshold.
+    """
+    covered_seconds: float
+    audio_seconds: float
+    fraction: float
+    likely_truncated: bool
+
+    def to_dict(self) -> Dict[str, Any]: ...
+
+
+class QuotaTracker:
+    """Project when a quota runs out from successive remaining-amount snapshots.
+
+    The SDK has no usage endpoint, so record the remaining amount you track
+    (minutes, credits, ...) each time you check it.
+    """
+
+    def __init__(self) -> None: ...
+
+    def record(self, remaining: float, timestamp: float | None = None) -> None:
+        """Add a snapshot.
+
+        Args:
+            remaining: Remaining quota at that moment.
+            timestamp: Unix time in seconds; defaults to now. Must be later
+                than the previous snapshot.
+        """
+        ...
+
+    def rate(self) -> float | None:
+        """Average consumption per second between the first and last snapshots."""
+        ...
+
+    def eta_seconds(self) -> float | None:
+        """Seconds after the last snapshot until exhaustion; None if not consuming."""
+        ...
+
+    def exhaustion_time(self) -> float | None:
+        """Projected unix time of exhaustion; None if not consuming."""
+        ...
+
+    def clear(self) -> None:
+        """Drop all snapshots."""
+        ...
+
+    def __len__(self) -> int: ...
+
+
+class RetryConfig:
+    """Exponential backoff for transient request failures.
+
+    Pass as ``retry=`` to the upload, status, export and translate methods.
+    Timeouts, dropped connections, HTTP 429 and 5xx errors are retried; other
+    errors, such as invalid input or authentication failures, are raised at
+    once. Each delay is jittered to between half and all of its nominal value.
+    """
+
+    max_attempts: int
+    initial_delay_seconds: float
+    multiplier: float
+    max_delay_seconds: float
+
+    def __init__(
+        self,
+        *,
+        max_attempts: int = 3,
+        initial_delay_seconds: float = 0.5,
+        multiplier: float = 2.0,
+        max_delay_seconds: float = 10.0,
+    ) -> None:
+        """Args:
+            max_attempts: Total attempts including the first one.
+            initial_delay_seconds: Delay before the first retry.
+            multiplier: Factor applied to the delay after each retry (>= 1).
+            max_delay_seconds: Upper bound for any single delay.
+        """
+        ...
+
+
+class DianyaError(Exception):
+    """Base class of every error raised by this package.
+
+    Attributes:
+        code: Error code reported by the SDK, e.g. ``"INVALID_INPUT"``, when
+            there is one.
+        status_code: HTTP status of the failed API call (e.g. 401 or 503),
+            or None when the error did not come from an HTTP response or the
+            status is unknown. Present on every subclass.
+    """
+
+    code: str
+    status_code: int | None
+
+
+class InvalidInputError(DianyaError):
+    """An argument was rejected before the request or by the API."""
+    ...
+
+
+class AuthError(DianyaError):
+    """The token is missing, invalid, expired or lacks permission (401/403)."""
+    ...
+
+
+class NetworkError(DianyaError):
+    """The request did not complete: timeout, refused or dropped connection."""
+    ...
+
+
+class RateLimitError(DianyaError):
+    """Too many requests were sent (429); back off before retrying."""
+    ...
+
+
+class ServerError(DianyaError):
+    """The API failed with a 5xx response."""
+    ...
+
+
+class TaskFailedError(DianyaError):
+    """A task that was polled until done ended in a failed status instead."""
+    ...
+
+
+class DianyaStreamError(DianyaError):
+    """Raised when the realtime stream fails, e.g. a start handshake timeout."""
+    ...
+
+
+class StreamClosedError(DianyaStreamError):
+    """Raised when starting, reading from or sending on a stream after
+    ``TranscribeStream.shutdown``."""
+    ...
+
 
 class TranscribeStream:
     """WebSocket-based real-time transcription stream client.
@@ -205,32 +665,104 @@ class TranscribeStream:
     via WebSocket connection. It handles connection management, message
     sending, and result receiving.
     """
-    def __init__(self, session_id: str) -> None:
+    def __init__(
+        self,
+        session_id: str,
+        *,
+        idle_timeout: float | None = None,
+        buffer_until_ready: bool = False,
+        raw_log: str | None = None,
+        auto_reconnect: bool = False,
+        max_reconnect_attempts: int = 3,
+        keepalive_interval_seconds: float | None = None,
+    ) -> None:
         """Initialize a transcription stream with a session ID.
         
         Args:
             session_id: Session ID obtained from create_session.
+            idle_timeout: Stop the stream automatically when nothing has been sent
+                for this many seconds after ``start()``. The reader then receives a
+                final ``{"type": "idle_timeout", ...}`` message before the stream ends.
+                Must be greater than zero.
+            buffer_until_ready: Queue frames passed to ``send_text``/``send_bytes``
+                before the stream is ready and flush them in order once
+                ``start()`` completes, instead of sending them immediately.
+            raw_log: Append a copy of every raw server frame, one per line, to
+                this file while messages are still delivered to readers as usual.
+                Teeing stops silently if a write fails.
+            auto_reconnect: When the connection ends without ``stop()``,
+                ``aclose()`` or an idle timeout, open a new one for the same
+                session, backing off exponentially between attempts. Readers
+                receive ``{"type": "reconnected", "attempt": n}`` after each
+                successful reconnect, or ``{"type": "reconnect_failed", ...}``
+                just before the stream ends when every attempt failed. Messages
+                sent by the server while disconnected are lost.
+            max_reconnect_attempts: Attempts per dropped connection.
+            keepalive_interval_seconds: Send a WebSocket ping this often while
+                the stream runs, so proxies do not close it during pauses in
+                the audio. Pings do not count as sends for ``idle_timeout`` or
+                ``stats()``. Stopped by ``stop()``, ``aclose()`` and
+                ``finish_and_collect()``. Disabled when None.
         """
         ...
 
     @staticmethod
     async def create_session(
-        model: ModelType, token: str
+        model: ModelType, token: str, *, max_attempts: int = 3
     ) -> SessionCreateResult:
         """Create a real-time transcription session.
 
+        Transient failures (timeouts, dropped connections, 429 and 5xx
+        responses) are retried with a short jittered, doubling backoff starting
+        at 0.5 seconds. Invalid input, authentication and other client errors
+        fail on the first attempt.
+
         Args:
             model: Transcription model type (speed, quality, or quality_v2).
             token: Bearer token for authentication.
+            max_attempts: Total attempts including the first, 1 to 5 (default: 3).
 
         Returns:
             Session creation result with task_id, session_id, usage_id, and max_time.
         """
         ...
 
+    @staticmethod
+    async def transcribe_file_streaming(
+        path: str,
+        model: ModelType,
+        token: str,
+        *,
+        chunk_seconds: float = 0.2,
+        realtime: bool = True,
+        finish_timeout: float = 30.0,
+    ) -> List[UtterancePayload]:
+        """Transcribe a local audio file through the real-time endpoint.
+
+        Creates a session, streams the file, collects final results and closes
+        the session, even when streaming fails.
+
+        Args:
+            path: 16 kHz mono 16-bit PCM audio, as a ``.wav`` file or raw ``.pcm``/``.raw``.
+            model: Transcription model type.
+            token: Bearer token for authentication.
+            chunk_seconds: Audio duration per binary frame (default: 0.2).
+            realtime: Pace frames at real-time speed (default: True).
+            finish_timeout: Seconds to wait after the last frame for its final
+                result before the stream is stopped (default: 30.0).
+
+        Returns:
+            Final utterances of the transcript, in arrival order.
+        """
+        ...
+
     @staticmethod
     async def close_session(
-        task_id: str, token: str, timeout_seconds: int | None = ...
+        task_id: str,
+        token: str,
+        timeout_seconds: int | None = ...,
+        *,
+        wait_finalized: bool = False,
     ) -> SessionCloseResult:
         """Close a real-time transcription session.
 
@@ -241,20 +773,62 @@ class TranscribeStream:
             task_id: Task ID obtained from create_session.
             token: Bearer token for authentication.
             timeout_seconds: Timeout in seconds (default: 30).
+            wait_finalized: After the close request, poll the task status once
+                a second until the server no longer reports the session as in
+                progress; ``status`` is then that final status. The close is
+                sent only once, and ``timeout_seconds`` bounds the whole wait.
 
         Returns:
             Session close result with status and optional duration/error information.
+
+        Raises:
+            TimeoutError: With ``wait_finalized``, if the session is still in
+                progress when the timeout expires.
         """
         ...
     
-    async def start(self) -> None:
-        """Start the WebSocket connection and begin receiving transcription results."""
+    async def start(self, timeout: float | None = None) -> None:
+        """Start the WebSocket connection and begin receiving transcription results.
+        
+        Args:
+            timeout: Seconds to wait for the start handshake. If None, wait
+                indefinitely.
+        
+        Raises:
+            DianyaStreamError: If the handshake does not complete in time. The
+                connection is stopped before the error is raised.
+        """
         ...
     
     async def stop(self) -> None:
         """Stop the WebSocket connection and send end signal to server."""
         ...
     
+    async def aclose(self) -> None:
+        """Release the stream completely.
+
+        Stops the connection and the idle watchdog, ends pending and future
+        reads, discards frames buffered by ``buffer_until_ready`` and closes the
+        raw log. Sends after closing raise ``StreamClosedError`` instead of
+        being buffered. Safe to call more than once; later calls do nothing.
+        """
+        ...
+
+    async def shutdown(self) -> None:
+        """Tear the stream down for good.
+
+        Does everything ``aclose`` does, including cancelling the keepalive
+        task, and leaves the stream terminal: ``start``, the ``read_next*``
+        methods, ``wait_for``, ``on_message``, ``finish_and_collect``, async
+        iteration and the ``send_*`` methods then raise ``StreamClosedError``.
+        Reads already waiting return None.
+        """
+        ...
+
+    def is_closed(self) -> bool:
+        """Whether ``aclose()`` has been called."""
+        ...
+    
     async def send_text(self, message: str) -> None:
         """Send a text message (control command) to the server.
         
@@ -270,7 +844,51 @@ class TranscribeStream:
             data: Binary audio frame data (bytes, bytearray, or memoryview).
         """
         ...
+
+    async def send_bytes_batch(self, frames: list[bytes | bytearray | memoryview]) -> None:
+        """Send several binary audio frames in order under a single socket lock.
+
+        Cheaper than calling ``send_bytes`` for each of many small chunks.
+
+        Args:
+            frames: Binary audio frames (bytes, bytearray, or memoryview).
+
+        Raises:
+            DianyaError: If a frame fails to send, as the same subclass
+                ``send_bytes`` would raise (e.g. ``NetworkError``); the message
+                names the index of that frame. Frames before it have already
+                been sent.
+        """
+        ...
+
+    async def send_from_async_iter(
+        self,
+        source: AsyncIterable[bytes | bytearray | memoryview],
+        *,
+        pace_seconds: float | None = None,
+    ) -> int:
+        """Send every chunk produced by an async iterable as a binary frame.
+
+        Each frame is sent before the next chunk is requested, so a slow
+        connection slows the producer down rather than queueing frames.
+
+        Args:
+            source: Async iterable of audio chunks, e.g. an async generator.
+            pace_seconds: Optional delay after each frame.
+
+        Returns:
+            Number of frames sent once ``source`` is exhausted.
+        """
+        ...
     
+    def is_ready(self) -> bool:
+        """Whether ``start()`` has completed its handshake."""
+        ...
+
+    def stats(self) -> StreamStats:
+        """Return a snapshot of the stream's send/receive counters."""
+        ...
+
     async def read_next(self, timeout: float | None = ...) -> str | None:
         """Read the next message from the stream.
         
@@ -283,25 +901,181 @@ class TranscribeStream:
         """
         ...
 
+    async def read_next_parsed(self, timeout: float | None = None) -> StreamMessage | None:
+        """Read the next message and decode it into a ``StreamMessage``.
 
-class TranscribeApi:
-    """Main API client for Dianya transcription and translation services.
-    
-    This class provides asynchronous methods for all transcription and
-    translation operations, including session management, file upload,
-    status queries, export, and translation.
-    """
-    def __init__(self) -> None:
-        """Initialize the API client."""
+        Malformed frames are returned with ``message_type="unknown"`` instead
+        of raising, so the raw payload is never lost.
+
+        Args:
+            timeout: Timeout in seconds. If None, wait indefinitely.
+
+        Returns:
+            The decoded message, or None on timeout or when the stream has ended.
+        """
         ...
 
-    async def transcribe_upload(
+    async def __aenter__(self) -> TranscribeStream:
+        """Start the stream (as ``start()`` without a timeout) and return it."""
+        ...
+
+    async def __aexit__(
         self,
-        filepath: str,
-        transcribe_only: bool,
+        exc_type: type[BaseException] | None,
+        exc_value: BaseException | None,
+        traceback: Any,
+    ) -> bool:
+        """Close the stream with ``aclose()``, whether or not the block raised.
+
+        If the block raised, an error while closing is suppressed so the
+        original exception propagates; otherwise it is raised. Never
+        suppresses the block's exception.
+        """
+        ...
+
+    def __aiter__(self) -> TranscribeStream: ...
+
+    async def __anext__(self) -> str:
+        """Next message, as ``read_next`` without a timeout.
+
+        Allows ``async for message in stream:``; iteration ends when the
+        stream does. Do not mix with ``on_message`` on the same stream.
+        """
+        ...
+
+    async def read_next_with_seq(
+        self, timeout: float | None = None
+    ) -> tuple[int, str] | None:
+        """Read the next message together with its sequence number.
+
+        Sequence numbers start at 1, increase by one per delivered message
+        (across all read methods) and keep counting across reconnects, so a
+        jump reveals messages consumed elsewhere.
+
+        Args:
+            timeout: Timeout in seconds. If None, wait indefinitely.
+
+        Returns:
+            ``(seq, message)``, or None on timeout or when the stream has ended.
+        """
+        ...
+
+    async def wait_for(
+        self, event_type: str, timeout: float | None = None
+    ) -> str | None:
+        """Discard messages until one of the given type arrives.
+
+        The type is matched case-insensitively against the message's ``type``
+        (or ``event``/``action``) field; ``"final"`` also matches messages
+        flagged ``is_final``.
+
+        Args:
+            event_type: Message type to wait for, e.g. ``"final"``.
+            timeout: Timeout in seconds. If None, wait indefinitely.
+
+        Returns:
+            The matching raw message, or None when the stream ends first.
+
+        Raises:
+            TimeoutError: If no matching message arrives before the timeout.
+        """
+        ...
+
+    async def on_message(
+        self, callback: Callable[[str], None] | Callable[[str], Awaitable[None]]
+    ) -> int:
+        """Push each incoming message to ``callback`` until the stream ends.
+
+        Calling this registers ``callback``: dispatching starts at once in a
+        background task owned by the stream, whether or not the result is
+        awaited. Registering again replaces the previous callback, and
+        ``aclose``/``shutdown`` cancel the task. Coroutine callbacks are
+        awaited, on the event loop that registered them, before the next
+        message is delivered. Do not mix with ``read_next``, as both consume
+        the same stream.
+
+        Args:
+            callback: Callable invoked with every message string.
+
+        Returns:
+            Number of messages dispatched once the stream ends, the callback is
+            replaced or the stream is closed.
+
+        Raises:
+            Exception: The first exception raised by ``callback``, which also
+                stops dispatching.
+        """
+        ...
+
+    async def finish_and_collect(self, timeout: float | None = None) -> List[UtterancePayload]:
+        """Collect the final transcript, then end the stream.
+
+        Reads remaining messages until a final result arrives or the stream
+        closes, then stops the stream (as ``stop()`` does), also when the
+        timeout expires. Partial results read on the way are discarded.
+
+        Args:
+            timeout: Seconds to wait for the final result. If None, wait indefinitely.
+
+        Returns:
+            Utterances of the final result; empty if the stream closed without one.
+
+        Raises:
+            TimeoutError: If no final result arrived before the timeout.
+        """
+        ...
+
+
+class TranscribeApi:
+    """Main API client for Dianya transcription and translation services.
+    
+    This class provides asynchronous methods for all transcription and
+    translation operations, including session management, file upload,
+    status queries, export, and translation. Each of them has a blocking
+    ``*_blocking`` counterpart for scripts without an event loop; it releases
+    the GIL while waiting. Blocking methods also work from coroutines and
+    stream callbacks, though they hold up the calling thread until done.
+    """
+    def __init__(
+        self,
+        *,
+        token: str | None = None,
+        debug_capture: int | None = None,
+        max_concurrency: int | None = None,
+    ) -> None:
+        """Initialize the API client.
+
+        Args:
+            token: Bearer token used by every method called without its own
+                ``token``. A per-call token always takes precedence.
+            debug_capture: Keep the last N request/response pairs for diagnosis.
+                Disabled when None.
+            max_concurrency: Maximum number of this client's calls in flight at
+                once; further calls wait for a free slot. Polling helpers hold
+                their slot until they finish. Unlimited when None.
+        """
+        ...
+
+    def debug_captures(self) -> List[CapturedExchange]:
+        """Return the captured exchanges, oldest first (empty when capture is disabled)."""
+        ...
+
+    def clear_debug_captures(self) -> None:
+        """Discard all captured exchanges."""
+        ...
+
+    async def transcribe_upload(
+        self,
+        filepath: str,
+        transcribe_only: bool,
         short_asr: bool,
         model: ModelType,
-        token: str,
+        token: str | None = None,
+        *,
+        fallback_model: ModelType | None = None,
+        retry: RetryConfig | None = None,
+        timeout_seconds: float | None = None,
+        skip_validation: bool = False,
     ) -> UploadResult:
         """Upload an audio file for transcription.
         
@@ -310,30 +1084,240 @@ class TranscribeApi:
             transcribe_only: Whether to transcribe only (no summary).
             short_asr: Whether to use one-sentence ASR mode (duration <= 3 minutes, file <= 50MB).
             model: Transcription model type.
-            token: Bearer token for authentication.
+            token: Bearer token for authentication; defaults to the
+                token the client was created with.
+            fallback_model: Model to retry with once if ``model`` is rejected as
+                unavailable. Other errors are raised without retrying. The model
+                actually used is reported in ``UploadResult.model``.
+            retry: Retry transient failures with backoff; no retries when None.
+            timeout_seconds: Give up after this many seconds, including any
+                retries, and raise ``TimeoutError``; no limit when None.
+            skip_validation: Skip the pre-flight check that raises
+                ``InvalidInputError`` for a missing or unreadable file and
+                warns about an unrecognized extension.
         
         Returns:
             Upload result: Normal mode returns task_id, one-sentence mode returns transcription directly.
         """
         ...
 
+    async def transcribe_upload_many(
+        self,
+        filepaths: Sequence[str],
+        transcribe_only: bool,
+        short_asr: bool,
+        model: ModelType,
+        token: str | None = None,
+        *,
+        max_concurrency: int = 4,
+        fallback_model: ModelType | None = None,
+        retry: RetryConfig | None = None,
+        skip_validation: bool = False,
+    ) -> List[UploadOutcome]:
+        """Upload several audio files concurrently.
+
+        A file that fails does not stop the others; check ``ok`` on each
+        outcome. A ``max_concurrency`` set on the client applies on top of
+        the per-call limit.
+
+        Args:
+            filepaths: Paths of the audio files.
+            transcribe_only: Same as in ``transcribe_upload``, for every file.
+            short_asr: Same as in ``transcribe_upload``, for every file.
+            model: Transcription model type.
+            token: Bearer token for authentication; defaults to the
+                token the client was created with.
+            max_concurrency: Most uploads in flight at once.
+            fallback_model: Same as in ``transcribe_upload``.
+            retry: Retry each file's transient failures with backoff; no
+                retries when None.
+            skip_validation: Skip the pre-flight file checks.
+
+        Returns:
+            One outcome per path, in input order.
+        """
+        ...
+
+    async def transcribe_upload_from_bytes(
+        self,
+        data: bytes | bytearray | memoryview,
+        filename: str,
+        transcribe_only: bool,
+        short_asr: bool,
+        model: ModelType,
+        token: str | None = None,
+        *,
+        fallback_model: ModelType | None = None,
+        retry: RetryConfig | None = None,
+        timeout_seconds: float | None = None,
+    ) -> UploadResult:
+        """Upload in-memory audio for transcription.
+
+        The SDK only uploads from a path, so ``data`` is written to a temporary
+        file for the duration of the upload and removed afterwards, also when
+        the upload fails or times out.
+
+        Args:
+            data: Audio file contents.
+            filename: Bare file name such as ``"meeting.wav"``; its extension
+                tells the API the audio format.
+            transcribe_only: Whether to transcribe only (no summary).
+            short_asr: Whether to use one-sentence ASR mode (duration <= 3 minutes, file <= 50MB).
+            model: Transcription model type.
+            token: Bearer token for authentication; defaults to the
+                token the client was created with.
+            fallback_model: Same as in ``transcribe_upload``.
+            retry: Retry transient failures with backoff; no retries when None.
+            timeout_seconds: Give up after this many seconds, including any
+                retries, and raise ``TimeoutError``; no limit when None.
+
+        Returns:
+            Same as ``transcribe_upload``.
+        """
+        ...
+
     async def transcribe_status(
-        self, task_id: str | None = ..., share_id: str | None = ..., *, token: str
+        self,
+        task_id: str | None = ...,
+        share_id: str | None = ...,
+        token: str | None = None,
+        *,
+        normalize_speakers: bool = False,
+        retry: RetryConfig | None = None,
+        timeout_seconds: float | None = None,
     ) -> StatusResponse:
         """Get transcription or summary task status.
         
         Args:
             task_id: Task ID (optional, mutually exclusive with share_id).
             share_id: Share link ID (optional, mutually exclusive with task_id).
-            token: Bearer token for authentication (keyword-only).
+            token: Bearer token for authentication; defaults to the
+                token the client was created with.
+            normalize_speakers: Renumber speakers in ``details`` to 0..N by
+                first appearance; the original ids are kept in ``speaker_map``.
+            retry: Retry transient failures with backoff; no retries when None.
+            timeout_seconds: Give up after this many seconds, including any
+                retries, and raise ``TimeoutError``; no limit when None.
         
         Returns:
             Task status with transcription results, summary, overview, etc.
         """
         ...
 
+    async def transcribe_wait_until_done(
+        self,
+        task_id: str | StatusResponse,
+        *,
+        token: str | None = None,
+        poll_interval_seconds: float = 2.0,
+        timeout_seconds: float | None = None,
+    ) -> StatusResponse:
+        """Poll ``transcribe_status`` until the task reaches a terminal status.
+
+        Statuses such as ``done``/``success`` end the wait; ``failed``/``error``
+        and similar raise. Anything else counts as still running.
+
+        Args:
+            task_id: Task ID, e.g. from ``transcribe_upload``, or a status
+                already fetched. A status that is already done is returned
+                as it is, and an already failed one raises, without a request.
+            token: Bearer token for authentication (keyword-only); defaults to the
+                token the client was created with.
+            poll_interval_seconds: Delay between polls in seconds, greater than
+                zero (default: 2.0).
+            timeout_seconds: Overall timeout in seconds. If None, wait indefinitely.
+
+        Returns:
+            The final status once the task is done.
+
+        Raises:
+            TaskFailedError: If the task ends in a failed status.
+            TimeoutError: If the timeout expires before the task finishes.
+        """
+        ...
+
+    async def transcribe_status_many(
+        self,
+        task_ids: Sequence[str],
+        *,
+        token: str | None = None,
+        dedupe: bool = False,
+        timeout_seconds: float | None = None,
+    ) -> List[StatusResponse]:
+        """Get the status of several tasks concurrently.
+
+        Results are in input order, one per input id. A repeated id is fetched
+        once per occurrence unless ``dedupe`` is set, in which case it is
+        fetched once and the same status is returned at each of its positions.
+        Each request counts separately against ``max_concurrency``.
+
+        Args:
+            task_ids: Task IDs to query.
+            token: Bearer token for authentication (keyword-only); defaults to the
+                token the client was created with.
+            dedupe: Fetch each distinct id only once.
+            timeout_seconds: Give up after this many seconds and raise
+                ``TimeoutError``; no limit when None.
+
+        Returns:
+            One status per entry of ``task_ids``.
+        """
+        ...
+
+    async def task_exists(
+        self,
+        task_id: str,
+        *,
+        token: str | None = None,
+        timeout_seconds: float | None = None,
+    ) -> bool:
+        """Check whether a task ID is known to the server.
+
+        Uses the status endpoint; a not-found response maps to ``False`` while
+        authentication and network errors are raised as usual.
+
+        Args:
+            task_id: Task ID to check.
+            token: Bearer token for authentication (keyword-only); defaults to the
+                token the client was created with.
+            timeout_seconds: Give up after this many seconds and raise
+                ``TimeoutError``; no limit when None.
+
+        Returns:
+            True if the task exists.
+        """
+        ...
+
+    async def get_keywords(
+        self,
+        task_id: str,
+        *,
+        token: str | None = None,
+        timeout_seconds: float | None = None,
+    ) -> List[str]:
+        """Get only the keywords of a task.
+
+        Uses the status endpoint but skips building the utterance details,
+        which keeps this cheap for long transcripts.
+
+        Args:
+            task_id: Task ID to query.
+            token: Bearer token for authentication (keyword-only); defaults to the
+                token the client was created with.
+            timeout_seconds: Give up after this many seconds and raise
+                ``TimeoutError``; no limit when None.
+
+        Returns:
+            Keywords extracted from the transcript; empty until the task is done.
+        """
+        ...
+
     async def transcribe_callback(
-        self, request: str | CallbackRequestPayload, *, token: str
+        self,
+        request: str | CallbackRequestPayload,
+        *,
+        token: str | None = None,
+        timeout_seconds: float | None = None,
     ) -> CallbackResponse:
         """Handle transcription task status callback.
         
@@ -342,7 +1326,10 @@ class TranscribeApi:
         
         Args:
             request: Callback request data (JSON string or dict).
-            token: Bearer token for authentication (keyword-only).
+            token: Bearer token for authentication (keyword-only); defaults to the
+                token the client was created with.
+            timeout_seconds: Give up after this many seconds and raise
+                ``TimeoutError``; no limit when None.
         
         Returns:
             Callback response with status.
@@ -350,14 +1337,22 @@ class TranscribeApi:
         ...
 
     async def transcribe_share_link(
-        self, task_id: str, expiration_days: int | None = ..., *, token: str
+        self,
+        task_id: str,
+        expiration_days: int | None = ...,
+        *,
+        token: str | None = None,
+        timeout_seconds: float | None = None,
     ) -> ShareLinkResponse:
         """Get a share link for a transcription task.
         
         Args:
             task_id: Task ID.
             expiration_days: Expiration time in days (optional, default: 7).
-            token: Bearer token for authentication (keyword-only).
+            token: Bearer token for authentication (keyword-only); defaults to the
+                token the client was created with.
+            timeout_seconds: Give up after this many seconds and raise
+                ``TimeoutError``; no limit when None.
         
         Returns:
             Share link response with share_url, expiration_time, and expired_at.
@@ -365,49 +1360,156 @@ class TranscribeApi:
         ...
 
     async def transcribe_create_summary(
-        self, utterances: Sequence[UtterancePayload], *, token: str
+        self,
+        utterances: Sequence[UtterancePayload],
+        *,
+        token: str | None = None,
+        timeout_seconds: float | None = None,
     ) -> SummaryCreateResponse:
         """Create a summary task from utterances.
         
         Args:
-            utterances: Sequence of utterance dictionaries with start_time, end_time, text, and speaker.
-            token: Bearer token for authentication (keyword-only).
+            utterances: Sequence of utterance dictionaries or ``UtterancePayload`` objects with
+                start_time, end_time, text, and speaker.
+            token: Bearer token for authentication (keyword-only); defaults to the
+                token the client was created with.
+            timeout_seconds: Give up after this many seconds and raise
+                ``TimeoutError``; no limit when None.
         
         Returns:
             Summary creation result with task_id.
         """
         ...
 
+    async def transcribe_create_summary_chunked(
+        self,
+        utterances: Sequence[UtterancePayload],
+        *,
+        token: str | None = None,
+        max_chunk_chars: int = 10_000,
+        combine: bool = False,
+        poll_interval_seconds: float = 2.0,
+        timeout_seconds: float | None = None,
+    ) -> ChunkedSummaryResponse:
+        """Create summaries for transcripts too long for a single request.
+
+        Utterances are split, in order, into chunks of at most ``max_chunk_chars``
+        characters of text and each chunk is summarized separately, up to four at
+        a time. With ``combine=True`` the chunk summaries are awaited and
+        summarized again into one combined summary.
+
+        Args:
+            utterances: Sequence of utterance dictionaries or ``UtterancePayload`` objects.
+            token: Bearer token for authentication (keyword-only); defaults to the
+                token the client was created with.
+            max_chunk_chars: Text budget per chunk (default: 10000).
+            combine: Also create a summary of the chunk summaries.
+            poll_interval_seconds: Delay between status polls while combining,
+                greater than zero (default: 2.0).
+            timeout_seconds: Overall timeout in seconds. If None, wait indefinitely.
+
+        Returns:
+            The created chunk summaries and the optional combined summary.
+        """
+        ...
+
     async def transcribe_export(
         self,
         task_id: str,
         type: ExportTypeLiteral,
         format: ExportFormatLiteral,
-        token: str,
-    ) -> bytes:
+        token: str | None = None,
+        *,
+        speaker_labels: Mapping[int, str] | None = None,
+        validate: bool = False,
+        output_path: str | None = None,
+        retry: RetryConfig | None = None,
+        timeout_seconds: float | None = None,
+    ) -> bytes | str:
         """Export transcription or summary content.
         
         Args:
             task_id: Task ID (required).
             type: Export type: transcript (note: summary tasks don't support this),
                   overview, or summary.
-            format: Export format: pdf (default), txt, or docx.
-            token: Bearer token for authentication.
+            format: Export format: pdf (default), txt, docx, srt, or vtt. SRT and
+                WebVTT are not offered by the server; they are rendered from the
+                transcript details with one cue per utterance (WebVTT cues carry
+                a ``<v Speaker N>`` voice tag) and are only available for the
+                transcript type. An empty transcript gives empty SRT bytes or a
+                bare ``WEBVTT`` header.
+            token: Bearer token for authentication; defaults to the
+                token the client was created with.
+            speaker_labels: Names to substitute for numeric speaker labels such
+                as ``说话人1`` or ``Speaker 1`` at the start of lines, keyed by
+                the number shown in the export. Applied client-side; only
+                supported for txt exports.
+            validate: Check the downloaded file with ``validate_export_bytes``
+                before returning it, raising ``InvalidInputError`` if it is
+                damaged (default: off). Ignored for srt and vtt.
+            output_path: Write the export to this file instead of returning the
+                bytes. The data goes to a uniquely named ``.part`` file next
+                to it first and is renamed into place, so a failure or a
+                concurrent export to the same path never leaves a partial file.
+            retry: Retry transient failures with backoff; no retries when None.
+            timeout_seconds: Give up after this many seconds, including any
+                retries, and raise ``TimeoutError``; no limit when None.
         
         Returns:
-            Binary data of the exported file, which can be saved as the corresponding format.
+            Binary data of the exported file, which can be saved as the corresponding format,
+            or ``output_path`` when given.
+        """
+        ...
+
+    async def transcribe_export_csv(
+        self,
+        task_id: str,
+        path: str,
+        *,
+        token: str | None = None,
+        timeout_seconds: float | None = None,
+    ) -> int:
+        """Write a task's transcript details to a CSV file.
+
+        Rows are written one by one from the Rust side, so no Python objects are
+        created for the utterances. Columns: start_time, end_time, speaker, text.
+
+        Args:
+            task_id: Task ID.
+            path: Destination CSV file path (overwritten if it exists).
+            token: Bearer token for authentication (keyword-only); defaults to the
+                token the client was created with.
+            timeout_seconds: Give up after this many seconds and raise
+                ``TimeoutError``; no limit when None.
+
+        Returns:
+            Number of rows written, excluding the header.
         """
         ...
 
     async def translate_text(
-        self, text: str, language: LanguageCode, *, token: str
+        self,
+        text: str,
+        language: LanguageCode,
+        *,
+        token: str | None = None,
+        glossary: Mapping[str, str] | None = None,
+        retry: RetryConfig | None = None,
+        timeout_seconds: float | None = None,
     ) -> TextTranslationResponse:
         """Translate a text string.
         
         Args:
             text: Text to translate.
             language: Target language code.
-            token: Bearer token for authentication (keyword-only).
+            token: Bearer token for authentication (keyword-only); defaults to the
+                token the client was created with.
+            glossary: Term overrides applied to the translated text client-side;
+                each key found in the output is replaced with its value. Map a
+                term to itself to pin a term the service leaves untranslated.
+            retry: Retry transient failures with backoff; no retries when None.
+            timeout_seconds: Give up after this many seconds, including any
+                retries, and raise ``TimeoutError``; no limit when None.
         
         Returns:
             Translation response with status and translated data.
@@ -415,14 +1517,27 @@ class TranscribeApi:
         ...
 
     async def translate_utterances(
-        self, utterances: Sequence[UtterancePayload], language: LanguageCode, *, token: str
+        self,
+        utterances: Sequence[UtterancePayload],
+        language: LanguageCode,
+        *,
+        token: str | None = None,
+        glossary: Mapping[str, str] | None = None,
+        retry: RetryConfig | None = None,
+        timeout_seconds: float | None = None,
     ) -> UtteranceTranslationResponse:
         """Translate a list of utterances.
         
         Args:
-            utterances: Sequence of utterance dictionaries to translate.
+            utterances: Sequence of utterance dictionaries or ``UtterancePayload`` objects to translate.
             language: Target language code.
-            token: Bearer token for authentication (keyword-only).
+            token: Bearer token for authentication (keyword-only); defaults to the
+                token the client was created with.
+            glossary: Term overrides applied to each translated utterance, as in
+                ``translate_text``.
+            retry: Retry transient failures with backoff; no retries when None.
+            timeout_seconds: Give up after this many seconds, including any
+                retries, and raise ``TimeoutError``; no limit when None.
         
         Returns:
             Translation response with status, target_language, and translated details.
@@ -430,14 +1545,24 @@ class TranscribeApi:
         ...
 
     async def translate_transcribe(
-        self, task_id: str, language: LanguageCode, *, token: str
+        self,
+        task_id: str,
+        language: LanguageCode,
+        *,
+        token: str | None = None,
+        retry: RetryConfig | None = None,
+        timeout_seconds: float | None = None,
     ) -> TranscribeTranslationResponse:
         """Get translation result for a transcription task.
         
         Args:
             task_id: Task ID.
             language: Target language code.
-            token: Bearer token for authentication (keyword-only).
+            token: Bearer token for authentication (keyword-only); defaults to the
+                token the client was created with.
+            retry: Retry transient failures with backoff; no retries when None.
+            timeout_seconds: Give up after this many seconds, including any
+                retries, and raise ``TimeoutError``; no limit when None.
         
         Returns:
             Translation response with task information and translated results including
@@ -445,10 +1570,662 @@ class TranscribeApi:
         """
         ...
 
+    async def poll_translation_until_done(
+        self,
+        task_id: str,
+        language: LanguageCode,
+        *,
+        token: str | None = None,
+        poll_interval_seconds: float = 2.0,
+        timeout_seconds: float | None = None,
+    ) -> TranscribeTranslationResponse:
+        """Poll ``translate_transcribe`` until the translation reaches a terminal status.
+
+        Args:
+            task_id: Task ID.
+            language: Target language code.
+            token: Bearer token for authentication (keyword-only); defaults to the
+                token the client was created with.
+            poll_interval_seconds: Delay between polls in seconds, greater than
+                zero (default: 2.0).
+            timeout_seconds: Overall timeout in seconds. If None, wait indefinitely.
+
+        Returns:
+            The final translation response once the status is done.
+
+        Raises:
+            TaskFailedError: If the translation ends in a failed status.
+            TimeoutError: If the timeout expires before the translation finishes.
+        """
+        ...
+
+    async def translate_with_summary(
+        self,
+        task_id: str,
+        language: LanguageCode,
+        *,
+        token: str | None = None,
+        poll_interval_seconds: float = 2.0,
+        timeout_seconds: float | None = None,
+    ) -> TranslatedSummaryResponse:
+        """Translate a task and summarize it in the same target language.
+
+        The summary endpoint has no language parameter, so the summary is
+        created from the translated transcript once the translation is done.
+        The summary therefore follows the translation's language, but the
+        server does not guarantee it.
+
+        Args:
+            task_id: Task ID.
+            language: Target language code for both outputs.
+            token: Bearer token for authentication (keyword-only); defaults to the
+                token the client was created with.
+            poll_interval_seconds: Delay between polls in seconds, greater than
+                zero (default: 2.0).
+            timeout_seconds: Overall timeout in seconds. If None, wait indefinitely.
+
+        Returns:
+            The finished translation and the finished summary task status.
+
+        Raises:
+            TaskFailedError: If the translation or the summary task fails.
+            TimeoutError: If the timeout expires before both finish.
+        """
+        ...
+
+    def transcribe_upload_blocking(
+        self,
+        filepath: str,
+        transcribe_only: bool,
+        short_asr: bool,
+        model: ModelType,
+        token: str | None = None,
+        *,
+        fallback_model: ModelType | None = None,
+        retry: RetryConfig | None = None,
+        timeout_seconds: float | None = None,
+        skip_validation: bool = False,
+    ) -> UploadResult:
+        """Blocking version of ``transcribe_upload``."""
+        ...
+
+    def transcribe_upload_many_blocking(
+        self,
+        filepaths: Sequence[str],
+        transcribe_only: bool,
+        short_asr: bool,
+        model: ModelType,
+        token: str | None = None,
+        *,
+        max_concurrency: int = 4,
+        fallback_model: ModelType | None = None,
+        retry: RetryConfig | None = None,
+        skip_validation: bool = False,
+    ) -> List[UploadOutcome]:
+        """Blocking version of ``transcribe_upload_many``."""
+        ...
+
+    def transcribe_upload_from_bytes_blocking(
+        self,
+        data: bytes | bytearray | memoryview,
+        filename: str,
+        transcribe_only: bool,
+        short_asr: bool,
+        model: ModelType,
+        token: str | None = None,
+        *,
+        fallback_model: ModelType | None = None,
+        retry: RetryConfig | None = None,
+        timeout_seconds: float | None = None,
+    ) -> UploadResult:
+        """Blocking version of ``transcribe_upload_from_bytes``."""
+        ...
+
+    def transcribe_status_blocking(
+        self,
+        task_id: str | None = ...,
+        share_id: str | None = ...,
+        token: str | None = None,
+        *,
+        normalize_speakers: bool = False,
+        retry: RetryConfig | None = None,
+        timeout_seconds: float | None = None,
+    ) -> StatusResponse:
+        """Blocking version of ``transcribe_status``."""
+        ...
+
+    def transcribe_wait_until_done_blocking(
+        self,
+        task_id: str | StatusResponse,
+        *,
+        token: str | None = None,
+        poll_interval_seconds: float = 2.0,
+        timeout_seconds: float | None = None,
+    ) -> StatusResponse:
+        """Blocking version of ``transcribe_wait_until_done``."""
+        ...
+
+    def transcribe_status_many_blocking(
+        self,
+        task_ids: Sequence[str],
+        *,
+        token: str | None = None,
+        dedupe: bool = False,
+        timeout_seconds: float | None = None,
+    ) -> List[StatusResponse]:
+        """Blocking version of ``transcribe_status_many``."""
+        ...
+
+    def task_exists_blocking(
+        self,
+        task_id: str,
+        *,
+        token: str | None = None,
+        timeout_seconds: float | None = None,
+    ) -> bool:
+        """Blocking version of ``task_exists``."""
+        ...
+
+    def get_keywords_blocking(
+        self,
+        task_id: str,
+        *,
+        token: str | None = None,
+        timeout_seconds: float | None = None,
+    ) -> List[str]:
+        """Blocking version of ``get_keywords``."""
+        ...
+
+    def transcribe_callback_blocking(
+        self,
+        request: str | CallbackRequestPayload,
+        *,
+        token: str | None = None,
+        timeout_seconds: float | None = None,
+    ) -> CallbackResponse:
+        """Blocking version of ``transcribe_callback``."""
+        ...
+
+    def transcribe_share_link_blocking(
+        self,
+        task_id: str,
+        expiration_days: int | None = ...,
+        *,
+        token: str | None = None,
+        timeout_seconds: float | None = None,
+    ) -> ShareLinkResponse:
+        """Blocking version of ``transcribe_share_link``."""
+        ...
+
+    def transcribe_create_summary_blocking(
+        self,
+        utterances: Sequence[UtterancePayload],
+        *,
+        token: str | None = None,
+        timeout_seconds: float | None = None,
+    ) -> SummaryCreateResponse:
+        """Blocking version of ``transcribe_create_summary``."""
+        ...
+
+    def transcribe_create_summary_chunked_blocking(
+        self,
+        utterances: Sequence[UtterancePayload],
+        *,
+        token: str | None = None,
+        max_chunk_chars: int = 10_000,
+        combine: bool = False,
+        poll_interval_seconds: float = 2.0,
+        timeout_seconds: float | None = None,
+    ) -> ChunkedSummaryResponse:
+        """Blocking version of ``transcribe_create_summary_chunked``."""
+        ...
+
+    def transcribe_export_blocking(
+        self,
+        task_id: str,
+        type: ExportTypeLiteral,
+        format: ExportFormatLiteral,
+        token: str | None = None,
+        *,
+        speaker_labels: Mapping[int, str] | None = None,
+        validate: bool = False,
+        output_path: str | None = None,
+        retry: RetryConfig | None = None,
+        timeout_seconds: float | None = None,
+    ) -> bytes | str:
+        """Blocking version of ``transcribe_export``."""
+        ...
+
+    def transcribe_export_csv_blocking(
+        self,
+        task_id: str,
+        path: str,
+        *,
+        token: str | None = None,
+        timeout_seconds: float | None = None,
+    ) -> int:
+        """Blocking version of ``transcribe_export_csv``."""
+        ...
+
+    def translate_text_blocking(
+        self,
+        text: str,
+        language: LanguageCode,
+        *,
+        token: str | None = None,
+        glossary: Mapping[str, str] | None = None,
+        retry: RetryConfig | None = None,
+        timeout_seconds: float | None = None,
+    ) -> TextTranslationResponse:
+        """Blocking version of ``translate_text``."""
+        ...
+
+    def translate_utterances_blocking(
+        self,
+        utterances: Sequence[UtterancePayload],
+        language: LanguageCode,
+        *,
+        token: str | None = None,
+        glossary: Mapping[str, str] | None = None,
+        retry: RetryConfig | None = None,
+        timeout_seconds: float | None = None,
+    ) -> UtteranceTranslationResponse:
+        """Blocking version of ``translate_utterances``."""
+        ...
+
+    def translate_transcribe_blocking(
+        self,
+        task_id: str,
+        language: LanguageCode,
+        *,
+        token: str | None = None,
+        retry: RetryConfig | None = None,
+        timeout_seconds: float | None = None,
+    ) -> TranscribeTranslationResponse:
+        """Blocking version of ``translate_transcribe``."""
+        ...
+
+    def poll_translation_until_done_blocking(
+        self,
+        task_id: str,
+        language: LanguageCode,
+        *,
+        token: str | None = None,
+        poll_interval_seconds: float = 2.0,
+        timeout_seconds: float | None = None,
+    ) -> TranscribeTranslationResponse:
+        """Blocking version of ``poll_translation_until_done``."""
+        ...
+
+    def translate_with_summary_blocking(
+        self,
+        task_id: str,
+        language: LanguageCode,
+        *,
+        token: str | None = None,
+        poll_interval_seconds: float = 2.0,
+        timeout_seconds: float | None = None,
+    ) -> TranslatedSummaryResponse:
+        """Blocking version of ``translate_with_summary``."""
+        ...
+
+
+def check_session_duration(
+    audio: str | float, max_time: float, *, strict: bool = False
+) -> float:
+    """Check that audio fits within a streaming session's ``max_time``.
+
+    Audio streamed past ``max_time`` is dropped by the server without an error,
+    so call this before streaming a file.
+
+    Args:
+        audio: Path to a WAV or raw 16 kHz mono PCM file, or a duration in seconds.
+        max_time: Allowed session length in seconds, e.g.
+            ``SessionCreateResult.max_time``.
+        strict: Raise instead of emitting a ``UserWarning`` when the audio is
+            too long.
+
+    Returns:
+        Audio duration in seconds.
+    """
+    ...
+
+
+def recommend_model(
+    audio: str | float,
+    *,
+    short_asr_max_seconds: float = 60.0,
+    quality_min_seconds: float = 600.0,
+) -> ModelRecommendation:
+    """Suggest upload settings for an audio length.
+
+    Clips up to ``short_asr_max_seconds`` use the cheaper one-sentence endpoint
+    (``short_asr`` and ``transcribe_only``), audio of at least
+    ``quality_min_seconds`` uses the quality model, and anything in between
+    uses the speed model.
+
+    Args:
+        audio: Path to a WAV or raw 16 kHz mono PCM file, or a duration in seconds.
+        short_asr_max_seconds: Longest clip sent to the one-sentence endpoint.
+        quality_min_seconds: Shortest audio worth the quality model.
+
+    Returns:
+        The recommended model and flags.
+    """
+    ...
+
+
+def to_ssml(
+    utterances: Sequence[UtterancePayload],
+    voice_map: Mapping[int, str] | None = None,
+    *,
+    language: str | None = None,
+    min_break_seconds: float = 0.3,
+) -> str:
+    """Render utterances as SSML for text-to-speech pipelines.
+
+    Each utterance becomes a ``<voice>`` element when its speaker appears in
+    ``voice_map`` (otherwise a plain ``<s>`` element). Gaps between utterances of
+    at least ``min_break_seconds`` are rendered as ``<break>`` elements, capped at
+    10 seconds.
+
+    Args:
+        utterances: Utterance dictionaries or ``UtterancePayload`` objects, in order.
+        voice_map: Mapping from speaker id to TTS voice name.
+        language: Optional ``xml:lang`` for the ``<speak>`` root.
+        min_break_seconds: Smallest gap rendered as a pause (default: 0.3).
+
+    Returns:
+        The SSML document as a string.
+    """
+    ...
+
+
+def to_bilingual_srt(
+    original: Sequence[UtterancePayload],
+    translation: UtteranceTranslationResponse,
+) -> str:
+    """Render a bilingual SRT with the original line above its translation.
+
+    Cues pair utterances by position and take their timing from the original.
+    If the counts differ, the unmatched utterances still get cues containing
+    only the line that exists. Pairs with no text at all are left out.
+
+    Args:
+        original: Utterances that were translated.
+        translation: Result of ``TranscribeApi.translate_utterances``.
+
+    Returns:
+        SRT document text.
+    """
+    ...
+
+
+def split_bilingual(text: str, *, delimiter: str = "\n") -> tuple[str, str | None]:
+    """Split a combined original+translation string into its two parts.
+
+    The text is split at the first ``delimiter`` and both parts are trimmed.
+    When the delimiter is missing or nothing follows it, the whole text is
+    returned as the original and the translation is None.
+
+    Args:
+        text: Combined text, e.g. ``"你好\nHello"``.
+        delimiter: Separator between the original and the translation.
+
+    Returns:
+        ``(original, translation)``.
+    """
+    ...
+
+
+def to_report_markdown(status: StatusResponse, *, title: str | None = None) -> str:
+    """Combine a task's overview, summary and keywords into one markdown document.
+
+    Sections appear in that order under ``## Overview``, ``## Summary`` and
+    ``## Keywords`` headings; missing or empty sections are omitted.
+
+    Args:
+        status: Status of a finished task.
+        title: Optional ``#`` heading placed at the top.
+
+    Returns:
+        Markdown text, empty if the status has none of the sections.
+    """
+    ...
+
+
+def export_plain_text(data: bytes, format: Literal["txt", "srt", "vtt"]) -> str:
+    """Extract the spoken text of a text-based export.
+
+    Cue numbers, timing lines, the WebVTT header, NOTE blocks and inline tags
+    are removed; remaining non-empty lines are joined with newlines.
+
+    Args:
+        data: Exported bytes.
+        format: Format of ``data``.
+
+    Returns:
+        The plain text.
+    """
+    ...
+
+
+def validate_export_bytes(data: bytes, format: ExportFormatLiteral) -> None:
+    """Check that an export is non-empty and not truncated.
+
+    PDFs must start with ``%PDF-`` and end with ``%%EOF``, DOCX files must be
+    complete ZIP archives and txt exports must be valid UTF-8.
+
+    Raises:
+        InvalidInputError: Describing what is wrong with the file.
+    """
+    ...
+
+
+def export_texts_match(
+    left: bytes,
+    left_format: Literal["txt", "srt", "vtt"],
+    right: bytes,
+    right_format: Literal["txt", "srt", "vtt"],
+) -> bool:
+    """Check whether two exports contain the same text.
+
+    Both sides are reduced with ``export_plain_text`` and then normalized to
+    lowercase letters and digits, ignoring whitespace and punctuation.
+    """
+    ...
+
+
+def validate_utterances(
+    utterances: Sequence[UtterancePayload],
+    *,
+    allow_overlap: bool = True,
+    max_overlap_seconds: float = 0.5,
+) -> List[str]:
+    """Check utterance timings and describe any problems found.
+
+    Flags negative start times, utterances that end before they start,
+    out-of-order utterances and overlaps between consecutive utterances.
+
+    Args:
+        utterances: Utterances to check, in transcript order.
+        allow_overlap: Tolerate short overlaps caused by crosstalk.
+        max_overlap_seconds: Longest overlap tolerated when ``allow_overlap``
+            is set (default: 0.5).
+
+    Returns:
+        One message per problem; empty if the timings are consistent.
+    """
+    ...
+
+
+def transcript_coverage(
+    utterances: Sequence[UtterancePayload],
+    audio_duration: float,
+    *,
+    threshold: float = 0.9,
+) -> CoverageReport:
+    """Detect transcripts that likely ended before the audio did.
+
+    Coverage runs up to the end of the last utterance, so silence within the
+    recording does not count against it.
+
+    Args:
+        utterances: Transcript details, e.g. ``StatusResponse.details``.
+        audio_duration: Length of the transcribed audio in seconds.
+        threshold: Coverage fraction below which the transcript is flagged
+            (default: 0.9).
+
+    Returns:
+        Coverage figures and the truncation flag.
+    """
+    ...
+
+
+def new_callback_attempts(
+    earlier: Sequence[CallbackHistoryItem], later: Sequence[CallbackHistoryItem]
+) -> List[CallbackHistoryItem]:
+    """Return callback attempts that appeared between two status fetches.
+
+    Args:
+        earlier: ``callback_history`` from the earlier fetch.
+        later: ``callback_history`` from the later fetch.
+
+    Returns:
+        Items of ``later`` whose timestamp is not in ``earlier``, in server order.
+    """
+    ...
+
+
+def merge_one_sentence_results(
+    results: Sequence[UploadResult | str],
+    durations: Sequence[float],
+    *,
+    gap_seconds: float = 0.0,
+    speaker: int = 0,
+) -> List[UtterancePayload]:
+    """Combine one-sentence uploads into a single transcript.
+
+    Each clip becomes one utterance placed right after the previous one, so
+    times are cumulative across the clips.
+
+    Args:
+        results: One-sentence ``UploadResult``s, or their recognized texts.
+        durations: Duration of each clip in seconds, in the same order.
+        gap_seconds: Silence inserted between clips (default: 0).
+        speaker: Speaker id assigned to every utterance (default: 0).
+
+    Returns:
+        One utterance per clip with sequential timing.
+    """
+    ...
+
+
+def split_sentences(utterances: Sequence[UtterancePayload]) -> List[UtterancePayload]:
+    """Split utterances into one utterance per sentence.
+
+    Sentences end at ``。！？；…``, which need no following space, and at
+    ``. ! ? ;`` followed by whitespace or the end of the text, so decimals and
+    abbreviations such as ``3.14`` stay whole. Each utterance's time span is
+    shared out in proportion to the non-whitespace characters of its
+    sentences; the speaker is kept.
+
+    Args:
+        utterances: Sequence of utterance dictionaries or ``UtterancePayload`` objects.
+
+    Returns:
+        The split utterances in order. Single-sentence utterances are unchanged.
+    """
+    ...
+
+
+def changed_translations(
+    previous: TranscribeTranslationResponse, current: TranscribeTranslationResponse
+) -> List[TranslationDetail]:
+    """Return the translated details that changed between two translations.
+
+    Details are matched by start time, end time and speaker. A detail counts as
+    changed when it is new or when its source text or translations differ.
+
+    Args:
+        previous: Earlier ``translate_transcribe`` result.
+        current: Result after re-translating.
+
+    Returns:
+        Changed details of ``current``, in order.
+    """
+    ...
+
+
+def unique_task_ids(task_ids: Sequence[str]) -> List[str]:
+    """Drop repeated task IDs, keeping the first occurrence of each in order."""
+    ...
+
+
+def language_from_native_name(name: str) -> LanguageCode:
+    """Map a language display name to its language code.
+
+    Accepts native names such as ``"日本語"`` or ``"Français"`` as well as English
+    names such as ``"German"``, case-insensitively. Unknown names raise an
+    ``INVALID_INPUT`` error.
+    """
+    ...
+
+
+def supported_languages() -> List[tuple[LanguageCode, str]]:
+    """List the translation languages as ``(code, display_name)`` pairs.
+
+    The codes are those accepted by the ``language`` arguments, e.g.
+    ``("zh", "Chinese (Simplified)")``; suitable for populating a picker.
+    """
+    ...
+
+
+def supported_models() -> List[ModelType]:
+    """List the names accepted by the ``model`` arguments, e.g. ``"speed"``.
+
+    ``UploadResult.model`` always holds one of these names.
+    """
+    ...
+
+
+def set_log_level(
+    level: Literal["off", "error", "warning", "info", "debug", "trace"],
+) -> None:
+    """Choose which records this package forwards to Python's ``logging``.
+
+    Records go to the ``dianyaapi`` logger and its children, e.g.
+    ``dianyaapi.transcribe_wrapper``. At ``"debug"`` every request is logged
+    with its duration (and HTTP status on failure), as are retries and
+    stream lifecycle changes. This also sets the ``dianyaapi`` logger to the
+    same level; ``"off"`` only stops forwarding. The default is
+    ``"warning"``.
+    """
+    ...
+
+
+def set_lenient_numbers(enabled: bool) -> None:
+    """Toggle coercion of string-encoded numbers when parsing payloads.
+
+    When enabled (the default), ``speaker``, ``start_time``, ``end_time`` and
+    ``max_time`` given as strings such as ``"1"`` are converted to numbers in
+    utterance sequences and callback payloads.
+    """
+    ...
+
 
 __all__ = [
     "TranscribeApi",
     "TranscribeStream",
+    "DianyaError",
+    "InvalidInputError",
+    "AuthError",
+    "NetworkError",
+    "RateLimitError",
+    "ServerError",
+    "TaskFailedError",
+    "DianyaStreamError",
+    "StreamClosedError",
     "ModelType",
     "ExportTypeLiteral",
     "ExportFormatLiteral",
@@ -456,6 +2233,7 @@ __all__ = [
     "SessionCreateResult",
     "SessionCloseResult",
     "UploadResult",
+    "UploadOutcome",
     "UtterancePayload",
     "SummaryContent",
     "CallbackHistoryItem",
@@ -463,9 +2241,39 @@ __all__ = [
     "CallbackResponse",
     "ShareLinkResponse",
     "SummaryCreateResponse",
+    "ChunkedSummaryResponse",
+    "TranslatedSummaryResponse",
     "TextTranslationResponse",
     "UtteranceTranslationResponse",
     "TranslationDetail",
     "TranscribeTranslationResponse",
+    "CapturedExchange",
+    "StreamMessage",
+    "StreamStats",
+    "CoverageReport",
+    "QuotaTracker",
+    "RetryConfig",
+    "ModelRecommendation",
+    "check_session_duration",
+    "recommend_model",
+    "to_ssml",
+    "to_bilingual_srt",
+    "split_bilingual",
+    "to_report_markdown",
+    "validate_utterances",
+    "transcript_coverage",
+    "new_callback_attempts",
+    "merge_one_sentence_results",
+    "split_sentences",
+    "changed_translations",
+    "unique_task_ids",
+    "set_lenient_numbers",
+    "set_log_level",
+    "language_from_native_name",
+    "supported_languages",
+    "supported_models",
+    "export_plain_text",
+    "export_texts_match",
+    "validate_export_bytes",
 ]
 
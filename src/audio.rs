@@ -0,0 +1,226 @@
+use crate::{errors::invalid_input, py_types::ModelRecommendation, types::parse_seconds};
+use pyo3::{exceptions::PyUserWarning, prelude::*};
+use std::{ffi::CString, path::Path};
+
+/// Format expected by the realtime endpoint: 16 kHz, mono, 16-bit PCM.
+pub const STREAM_SAMPLE_RATE: u32 = 16_000;
+pub const STREAM_CHANNELS: u16 = 1;
+pub const STREAM_BITS_PER_SAMPLE: u16 = 16;
+pub const STREAM_BYTE_RATE: u32 =
+    STREAM_SAMPLE_RATE * STREAM_CHANNELS as u32 * STREAM_BITS_PER_SAMPLE as u32 / 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PcmFormat {
+    pub audio_format: u16,
+    pub channels: u16,
+    pub sample_rate: u32,
+    pub bits_per_sample: u16,
+}
+
+/// Samples of a WAV file together with the format declared in its header.
+pub struct WavAudio<'a> {
+    pub format: PcmFormat,
+    pub samples: &'a [u8],
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+pub fn parse_wav(data: &[u8]) -> PyResult<WavAudio<'_>> {
+    if data.len() < 12 || &data[0..4] != b"RIFF" || &data[8..12] != b"WAVE" {
+        return Err(invalid_input("not a RIFF/WAVE file"));
+    }
+
+    let mut format = None;
+    let mut offset = 12;
+    while offset + 8 <= data.len() {
+        let id = &data[offset..offset + 4];
+        let size = read_u32(data, offset + 4).unwrap_or_default() as usize;
+        let body = offset + 8;
+        let end = body.saturating_add(size).min(data.len());
+        match id {
+            b"fmt " => {
+                format = Some(PcmFormat {
+                    audio_format: read_u16(data, body).unwrap_or_default(),
+                    channels: read_u16(data, body + 2).unwrap_or_default(),
+                    sample_rate: read_u32(data, body + 4).unwrap_or_default(),
+                    bits_per_sample: read_u16(data, body + 14).unwrap_or_default(),
+                });
+            }
+            b"data" => {
+                let format =
+                    format.ok_or_else(|| invalid_input("WAV data chunk precedes fmt chunk"))?;
+                return Ok(WavAudio {
+                    format,
+                    samples: &data[body..end],
+                });
+            }
+            _ => {}
+        }
+        // Chunks are padded to an even number of bytes.
+        offset = body.saturating_add(size + size % 2);
+    }
+    Err(invalid_input("WAV file has no data chunk"))
+}
+
+fn has_extension(path: &str, extensions: &[&str]) -> bool {
+    Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            extensions
+                .iter()
+                .any(|known| ext.eq_ignore_ascii_case(known))
+        })
+}
+
+/// Containers the upload endpoint is known to accept.
+const UPLOAD_EXTENSIONS: [&str; 16] = [
+    "wav", "wave", "mp3", "m4a", "aac", "flac", "ogg", "opus", "wma", "amr", "mp4", "mov", "m4v",
+    "webm", "mkv", "avi",
+];
+
+/// Pre-flight check for an upload, so a mistyped path fails here instead of
+/// deep inside the SDK. Unknown extensions only warn: the API may still
+/// accept the file.
+pub fn check_upload_file(py: Python<'_>, path: &str) -> PyResult<()> {
+    let metadata = std::fs::metadata(path)
+        .map_err(|err| invalid_input(format!("cannot upload '{path}': {err}")))?;
+    if !metadata.is_file() {
+        return Err(invalid_input(format!("cannot upload '{path}': not a file")));
+    }
+    std::fs::File::open(path)
+        .map_err(|err| invalid_input(format!("cannot upload '{path}': {err}")))?;
+
+    if !has_extension(path, &UPLOAD_EXTENSIONS) {
+        let message = format!(
+            "'{path}' does not have a recognized audio or video extension ({}); \
+             the upload may be rejected",
+            UPLOAD_EXTENSIONS.join(", ")
+        );
+        let message = CString::new(message).unwrap_or_default();
+        PyErr::warn(py, &py.get_type::<PyUserWarning>(), &message, 1)?;
+    }
+    Ok(())
+}
+
+/// Raw samples ready for the realtime endpoint. WAV files must already be in
+/// the stream format; `.pcm`/`.raw` files are assumed to be.
+pub fn stream_samples<'a>(path: &str, data: &'a [u8]) -> PyResult<&'a [u8]> {
+    if has_extension(path, &["pcm", "raw"]) {
+        return Ok(data);
+    }
+    if !has_extension(path, &["wav", "wave"]) {
+        return Err(invalid_input(format!(
+            "'{path}' cannot be streamed: \
+             only 16 kHz mono 16-bit WAV or raw PCM files are supported"
+        )));
+    }
+
+    let wav = parse_wav(data)?;
+    let format = wav.format;
+    if format.audio_format != 1
+        || format.sample_rate != STREAM_SAMPLE_RATE
+        || format.channels != STREAM_CHANNELS
+        || format.bits_per_sample != STREAM_BITS_PER_SAMPLE
+    {
+        return Err(invalid_input(format!(
+            "'{path}' is {} Hz, {} channel(s), {}-bit (format {}); \
+             streaming needs 16000 Hz mono 16-bit PCM",
+            format.sample_rate, format.channels, format.bits_per_sample, format.audio_format
+        )));
+    }
+    Ok(wav.samples)
+}
+
+/// Playback length in seconds. Raw PCM is assumed to be in the stream format.
+pub fn audio_duration(path: &str, data: &[u8]) -> PyResult<f64> {
+    if has_extension(path, &["pcm", "raw"]) {
+        return Ok(data.len() as f64 / STREAM_BYTE_RATE as f64);
+    }
+    let wav = parse_wav(data)?;
+    let format = wav.format;
+    let byte_rate =
+        format.sample_rate as f64 * format.channels as f64 * format.bits_per_sample as f64 / 8.0;
+    if byte_rate <= 0.0 {
+        return Err(invalid_input(format!(
+            "'{path}' has an invalid WAV format header"
+        )));
+    }
+    Ok(wav.samples.len() as f64 / byte_rate)
+}
+
+/// Duration in seconds of either a number of seconds or an audio file path.
+fn duration_of(audio: &Bound<'_, PyAny>) -> PyResult<f64> {
+    if let Ok(seconds) = audio.extract::<f64>() {
+        return Ok(seconds);
+    }
+    let path: String = audio.extract()?;
+    let data = std::fs::read(&path)
+        .map_err(|err| invalid_input(format!("cannot read '{path}': {err}")))?;
+    audio_duration(&path, &data)
+}
+
+#[pyfunction]
+#[pyo3(signature = (audio, max_time, *, strict=false))]
+pub fn check_session_duration(
+    py: Python<'_>,
+    audio: Bound<'_, PyAny>,
+    max_time: f64,
+    strict: bool,
+) -> PyResult<f64> {
+    let duration = duration_of(&audio)?;
+
+    if duration > max_time {
+        let message = format!(
+            "audio is {duration:.1}s long but the session allows {max_time}s; \
+             audio past the limit would be dropped"
+        );
+        if strict {
+            return Err(invalid_input(message));
+        }
+        let message = CString::new(message).unwrap_or_default();
+        PyErr::warn(py, &py.get_type::<PyUserWarning>(), &message, 1)?;
+    }
+    Ok(duration)
+}
+
+/// Short clips go to the one-sentence endpoint, long recordings to the
+/// quality model, and everything in between to the speed model.
+#[pyfunction]
+#[pyo3(signature = (audio, *, short_asr_max_seconds=60.0, quality_min_seconds=600.0))]
+pub fn recommend_model(
+    audio: Bound<'_, PyAny>,
+    short_asr_max_seconds: f64,
+    quality_min_seconds: f64,
+) -> PyResult<ModelRecommendation> {
+    let duration = duration_of(&audio)?;
+    parse_seconds(short_asr_max_seconds, "short_asr_max_seconds")?;
+    parse_seconds(quality_min_seconds, "quality_min_seconds")?;
+    if short_asr_max_seconds > quality_min_seconds {
+        return Err(invalid_input(
+            "short_asr_max_seconds must not exceed quality_min_seconds",
+        ));
+    }
+
+    let (model, short_asr) = if duration <= short_asr_max_seconds {
+        ("speed", true)
+    } else if duration >= quality_min_seconds {
+        ("quality", false)
+    } else {
+        ("speed", false)
+    };
+    Ok(ModelRecommendation {
+        model: model.to_string(),
+        short_asr,
+        transcribe_only: short_asr,
+        duration,
+    })
+}
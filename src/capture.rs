@@ -0,0 +1,123 @@
+use pyo3::prelude::*;
+use serde_json::Value;
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+    sync::{Arc, Mutex},
+};
+
+#[pyclass(module = "dianyaapi")]
+#[derive(Clone, Debug)]
+pub struct CapturedExchange {
+    #[pyo3(get)]
+    method: String,
+    #[pyo3(get)]
+    request: String,
+    #[pyo3(get)]
+    response: Option<String>,
+    #[pyo3(get)]
+    error: Option<String>,
+}
+
+/// Ring buffer of the most recent request/response pairs of one client.
+#[derive(Clone, Debug)]
+pub struct DebugCapture {
+    capacity: usize,
+    entries: Arc<Mutex<VecDeque<CapturedExchange>>>,
+}
+
+impl DebugCapture {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+        }
+    }
+
+    pub fn record(&self, method: &str, request: Value, outcome: Result<String, String>) {
+        let (response, error) = match outcome {
+            Ok(response) => (Some(response), None),
+            Err(error) => (None, Some(error)),
+        };
+        let exchange = CapturedExchange {
+            method: method.to_string(),
+            request: request.to_string(),
+            response,
+            error,
+        };
+
+        let mut entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        if entries.len() == self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(exchange);
+    }
+
+    pub fn snapshot(&self) -> Vec<CapturedExchange> {
+        let entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        entries.iter().cloned().collect()
+    }
+
+    pub fn clear(&self) {
+        let mut entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        entries.clear();
+    }
+}
+
+pub fn describe<T: Debug, E: Display>(result: &Result<T, E>) -> Result<String, String> {
+    result
+        .as_ref()
+        .map(|value| format!("{value:?}"))
+        .map_err(ToString::to_string)
+}
+
+/// Keeps the auth scheme and the last four characters so captures stay
+/// distinguishable without leaking the credential.
+pub fn redact_token(token: &str) -> String {
+    let (scheme, secret) = match token.split_once(' ') {
+        Some((scheme, secret)) => (format!("{scheme} "), secret),
+        None => (String::new(), token),
+    };
+    let chars: Vec<char> = secret.chars().collect();
+    if chars.len() <= 8 {
+        return format!("{scheme}***");
+    }
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("{scheme}***{tail}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn redact_token_keeps_scheme_and_tail() {
+        assert_eq!(redact_token("Bearer abcdefghijkl"), "Bearer ***ijkl");
+        assert_eq!(redact_token("abcdefghijkl"), "***ijkl");
+    }
+
+    #[test]
+    fn redact_token_hides_short_tokens_entirely() {
+        assert_eq!(redact_token("Bearer abcd"), "Bearer ***");
+        assert_eq!(redact_token("12345678"), "***");
+        assert_eq!(redact_token(""), "***");
+    }
+
+    #[test]
+    fn capture_keeps_only_the_latest_exchanges() {
+        let capture = DebugCapture::new(2);
+        for method in ["status", "export", "summary"] {
+            capture.record(method, json!({}), Ok("{}".to_string()));
+        }
+        let methods: Vec<_> = capture
+            .snapshot()
+            .into_iter()
+            .map(|exchange| exchange.method)
+            .collect();
+        assert_eq!(methods, ["export", "summary"]);
+
+        capture.clear();
+        assert!(capture.snapshot().is_empty());
+    }
+}
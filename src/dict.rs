@@ -0,0 +1,174 @@
+use crate::{errors::invalid_input, types::lenient_numbers};
+use pyo3::{
+    IntoPyObjectExt,
+    prelude::*,
+    types::{PyDict, PyList, PyMapping, PyString},
+};
+use std::{collections::HashMap, hash::Hash};
+
+/// Plain-Python conversion of a field value for `to_dict()`: nested response
+/// classes become dicts, so the result is directly JSON-serializable.
+pub trait DictValue {
+    fn dict_value<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>>;
+}
+
+macro_rules! plain_dict_value {
+    ($($ty:ty),*) => {
+        $(impl DictValue for $ty {
+            fn dict_value<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+                self.clone().into_bound_py_any(py)
+            }
+        })*
+    };
+}
+
+plain_dict_value!(String, bool, i32, u32, u64, f64);
+
+impl<T: DictValue> DictValue for Option<T> {
+    fn dict_value<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            Some(value) => value.dict_value(py),
+            None => Ok(py.None().into_bound(py)),
+        }
+    }
+}
+
+impl<T: DictValue> DictValue for Vec<T> {
+    fn dict_value<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let items = self
+            .iter()
+            .map(|item| item.dict_value(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new(py, items)?.into_any())
+    }
+}
+
+impl<K: DictValue, V: DictValue> DictValue for HashMap<K, V> {
+    fn dict_value<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let dict = PyDict::new(py);
+        for (key, value) in self {
+            dict.set_item(key.dict_value(py)?, value.dict_value(py)?)?;
+        }
+        Ok(dict.into_any())
+    }
+}
+
+/// Inverse of `DictValue` for `from_dict()`. `path` names the value in error
+/// messages, e.g. `StatusResponse.details[2].speaker`.
+pub trait FromDictValue: Sized {
+    fn from_dict_value(value: &Bound<'_, PyAny>, path: &str) -> PyResult<Self>;
+
+    /// Value used when the key is absent; only optional fields have one.
+    fn missing(path: &str) -> PyResult<Self> {
+        Err(invalid_input(format!("{path} is missing")))
+    }
+}
+
+pub fn mismatched(value: &Bound<'_, PyAny>, path: &str, expected: &str) -> PyErr {
+    match value.get_type().name() {
+        Ok(type_name) => invalid_input(format!("{path} must be {expected}, got {type_name}")),
+        Err(err) => err,
+    }
+}
+
+/// `key` of `mapping`, converted with `path.key` as its path.
+pub fn dict_field<T: FromDictValue>(
+    mapping: &Bound<'_, PyMapping>,
+    path: &str,
+    key: &str,
+) -> PyResult<T> {
+    let path = format!("{path}.{key}");
+    if !mapping.contains(key)? {
+        return T::missing(&path);
+    }
+    T::from_dict_value(&mapping.get_item(key)?, &path)
+}
+
+impl FromDictValue for String {
+    fn from_dict_value(value: &Bound<'_, PyAny>, path: &str) -> PyResult<Self> {
+        value
+            .extract()
+            .map_err(|_| mismatched(value, path, "a string"))
+    }
+}
+
+impl FromDictValue for bool {
+    fn from_dict_value(value: &Bound<'_, PyAny>, path: &str) -> PyResult<Self> {
+        value
+            .extract()
+            .map_err(|_| mismatched(value, path, "a bool"))
+    }
+}
+
+/// Numbers also accept numeric strings while lenient numbers are enabled,
+/// which covers map keys that went through JSON.
+macro_rules! numeric_from_dict_value {
+    ($($ty:ty),*) => {
+        $(impl FromDictValue for $ty {
+            fn from_dict_value(value: &Bound<'_, PyAny>, path: &str) -> PyResult<Self> {
+                if let Ok(number) = value.extract::<$ty>() {
+                    return Ok(number);
+                }
+                if lenient_numbers()
+                    && let Ok(text) = value.extract::<String>()
+                    && let Ok(number) = text.trim().parse::<$ty>()
+                {
+                    return Ok(number);
+                }
+                Err(mismatched(value, path, "a number"))
+            }
+        })*
+    };
+}
+
+numeric_from_dict_value!(i32, u32, u64, f64);
+
+impl<T: FromDictValue> FromDictValue for Option<T> {
+    fn from_dict_value(value: &Bound<'_, PyAny>, path: &str) -> PyResult<Self> {
+        if value.is_none() {
+            return Ok(None);
+        }
+        T::from_dict_value(value, path).map(Some)
+    }
+
+    fn missing(_path: &str) -> PyResult<Self> {
+        Ok(None)
+    }
+}
+
+impl<T: FromDictValue> FromDictValue for Vec<T> {
+    fn from_dict_value(value: &Bound<'_, PyAny>, path: &str) -> PyResult<Self> {
+        // A string is iterable too, but never a valid list here.
+        if value.is_instance_of::<PyString>() {
+            return Err(mismatched(value, path, "a list"));
+        }
+        let items = value
+            .try_iter()
+            .map_err(|_| mismatched(value, path, "a list"))?;
+        items
+            .enumerate()
+            .map(|(index, item)| T::from_dict_value(&item?, &format!("{path}[{index}]")))
+            .collect()
+    }
+}
+
+impl<K: FromDictValue + Eq + Hash, V: FromDictValue> FromDictValue for HashMap<K, V> {
+    fn from_dict_value(value: &Bound<'_, PyAny>, path: &str) -> PyResult<Self> {
+        // Any mapping is accepted, not just dicts, like the classes' from_dict.
+        let Ok(mapping) = value.cast::<PyMapping>() else {
+            return Err(mismatched(value, path, "a dict"));
+        };
+        mapping
+            .items()?
+            .iter()
+            .map(|item| {
+                let (key, value): (Bound<'_, PyAny>, Bound<'_, PyAny>) = item.extract()?;
+                let value_path = format!("{path}[{}]", key.repr()?);
+                Ok((
+                    K::from_dict_value(&key, &format!("{path} key"))?,
+                    V::from_dict_value(&value, &value_path)?,
+                ))
+            })
+            .collect()
+    }
+}
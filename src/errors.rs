@@ -0,0 +1,257 @@
+use common::Error;
+use pyo3::{create_exception, exceptions::PyException, prelude::*};
+
+create_exception!(
+    dianyaapi,
+    DianyaError,
+    PyException,
+    "Base class of every error raised by this package."
+);
+create_exception!(
+    dianyaapi,
+    InvalidInputError,
+    DianyaError,
+    "An argument was rejected before or by the API."
+);
+create_exception!(
+    dianyaapi,
+    AuthError,
+    DianyaError,
+    "The token is missing, invalid, expired or not allowed to do this."
+);
+create_exception!(
+    dianyaapi,
+    NetworkError,
+    DianyaError,
+    "The request did not complete: timeout, refused or dropped connection."
+);
+create_exception!(
+    dianyaapi,
+    RateLimitError,
+    DianyaError,
+    "The API refused the request because too many were sent."
+);
+create_exception!(
+    dianyaapi,
+    ServerError,
+    DianyaError,
+    "The API failed with a 5xx response."
+);
+create_exception!(
+    dianyaapi,
+    TaskFailedError,
+    DianyaError,
+    "A task polled until done ended in a failed status instead."
+);
+create_exception!(
+    dianyaapi,
+    DianyaStreamError,
+    DianyaError,
+    "Realtime stream failed outside the SDK's own error reporting."
+);
+create_exception!(
+    dianyaapi,
+    StreamClosedError,
+    DianyaStreamError,
+    "The stream was shut down and cannot be started, read or sent on again."
+);
+
+/// Words that introduce a status code, as in `HTTP 503`, `status: 404` or
+/// `client error (429 Too Many Requests)`.
+const STATUS_PREFIXES: [&str; 5] = ["http", "status", "code", "error", "状态码"];
+/// First words of the reason phrases that follow a code, as in `404 Not Found`.
+const REASON_WORDS: [&str; 14] = [
+    "bad",
+    "unauthorized",
+    "payment",
+    "forbidden",
+    "not",
+    "method",
+    "request",
+    "conflict",
+    "gone",
+    "payload",
+    "unprocessable",
+    "too",
+    "internal",
+    "service",
+];
+
+/// First HTTP error status mentioned in an SDK error message. The SDK's
+/// errors carry no response code of their own, only their messages do, so a
+/// 4xx/5xx number only counts when a status word comes right before it or a
+/// reason phrase right after: `processed 404 items` names no status.
+pub fn http_status(message: &str) -> Option<u16> {
+    let words: Vec<String> = message
+        .split(|ch: char| !ch.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    words.iter().enumerate().find_map(|(index, word)| {
+        if word.len() != 3 || !word.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+        let status = word
+            .parse()
+            .ok()
+            .filter(|status| (400..600).contains(status))?;
+        let prefixed = index
+            .checked_sub(1)
+            .is_some_and(|before| STATUS_PREFIXES.contains(&words[before].as_str()));
+        let reason = words
+            .get(index + 1)
+            .is_some_and(|after| REASON_WORDS.contains(&after.as_str()));
+        (prefixed || reason).then_some(status)
+    })
+}
+
+fn classify(message: &str, status: Option<u16>) -> PyErr {
+    let lower = message.to_ascii_lowercase();
+    let mentions = |words: &[&str]| words.iter().any(|word| lower.contains(word));
+    match status {
+        Some(401 | 403) => AuthError::new_err(message.to_string()),
+        Some(429) => RateLimitError::new_err(message.to_string()),
+        Some(500..=599) => ServerError::new_err(message.to_string()),
+        _ if mentions(&[
+            "unauthorized",
+            "forbidden",
+            "invalid token",
+            "token expired",
+        ]) =>
+        {
+            AuthError::new_err(message.to_string())
+        }
+        _ if mentions(&["too many requests", "rate limit"]) => {
+            RateLimitError::new_err(message.to_string())
+        }
+        _ if mentions(&["timed out", "timeout", "connection", "dns", "unreachable"]) => {
+            NetworkError::new_err(message.to_string())
+        }
+        _ => DianyaError::new_err(message.to_string()),
+    }
+}
+
+/// Maps an SDK error onto the exception hierarchy. The exception keeps the
+/// `code` the SDK would have attached and gains a `status_code`, which is
+/// `None` when the message names no HTTP status.
+pub fn to_py_err(err: Error) -> PyErr {
+    let message = err.to_string();
+    let status = http_status(&message);
+    let mapped = match &err {
+        Error::InvalidInput(message) => InvalidInputError::new_err(message.clone()),
+        _ => classify(&message, status),
+    };
+    let sdk = PyErr::from(err);
+    Python::attach(|py| {
+        // Setting attributes only fails on exceptions with `__slots__`, which
+        // none of the classes above define.
+        let value = mapped.value(py);
+        if let Ok(code) = sdk.value(py).getattr("code") {
+            let _ = value.setattr("code", code);
+        }
+        let _ = value.setattr("status_code", status);
+    });
+    mapped
+}
+
+/// `to_py_err` with `context` in front of the message. The class and the
+/// `code`/`status_code` attributes are kept, so `except AuthError` still
+/// matches, and the unprefixed error is chained as the cause.
+pub fn to_py_err_in(err: Error, context: &str) -> PyErr {
+    let mapped = to_py_err(err);
+    Python::attach(|py| {
+        let original = mapped.value(py);
+        let wrapped = PyErr::from_type(mapped.get_type(py), format!("{context}: {original}"));
+        let value = wrapped.value(py);
+        for attribute in ["code", "status_code"] {
+            if let Ok(field) = original.getattr(attribute) {
+                let _ = value.setattr(attribute, field);
+            }
+        }
+        wrapped.set_cause(py, Some(mapped.clone_ref(py)));
+        wrapped
+    })
+}
+
+pub fn invalid_input(message: impl Into<String>) -> PyErr {
+    to_py_err(Error::InvalidInput(message.into()))
+}
+
+pub fn register(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    // Class-level default, so exceptions raised without going through
+    // `to_py_err` (e.g. stream failures) still answer `status_code`.
+    py.get_type::<DianyaError>()
+        .setattr("status_code", py.None())?;
+    m.add("DianyaError", py.get_type::<DianyaError>())?;
+    m.add("InvalidInputError", py.get_type::<InvalidInputError>())?;
+    m.add("AuthError", py.get_type::<AuthError>())?;
+    m.add("NetworkError", py.get_type::<NetworkError>())?;
+    m.add("RateLimitError", py.get_type::<RateLimitError>())?;
+    m.add("ServerError", py.get_type::<ServerError>())?;
+    m.add("TaskFailedError", py.get_type::<TaskFailedError>())?;
+    m.add("DianyaStreamError", py.get_type::<DianyaStreamError>())?;
+    m.add("StreamClosedError", py.get_type::<StreamClosedError>())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn http_status_reads_prefixed_codes() {
+        assert_eq!(http_status("HTTP 503"), Some(503));
+        assert_eq!(http_status("request failed with status: 404"), Some(404));
+        assert_eq!(http_status("error code=401, invalid token"), Some(401));
+        assert_eq!(http_status("请求失败，状态码：429"), Some(429));
+    }
+
+    #[test]
+    fn http_status_reads_reason_phrases() {
+        assert_eq!(
+            http_status("HTTP status client error (404 Not Found) for url (https://x/y)"),
+            Some(404)
+        );
+        assert_eq!(http_status("502 Bad Gateway"), Some(502));
+        assert_eq!(http_status("got 429 Too Many Requests"), Some(429));
+    }
+
+    #[test]
+    fn http_status_ignores_other_numbers() {
+        assert_eq!(http_status("processed 404 items"), None);
+        assert_eq!(http_status("uploaded 512 bytes"), None);
+        assert_eq!(http_status("status 200"), None);
+        assert_eq!(http_status("HTTP 4040"), None);
+        assert_eq!(http_status("task 1404 failed"), None);
+    }
+
+    #[test]
+    fn classify_prefers_the_status() {
+        Python::initialize();
+        Python::attach(|py| {
+            let class = |message: &str| {
+                let err = classify(message, http_status(message));
+                err.get_type(py)
+                    .name()
+                    .map(|name| name.to_string())
+                    .unwrap_or_default()
+            };
+            assert_eq!(class("HTTP 401"), "AuthError");
+            assert_eq!(class("HTTP 429"), "RateLimitError");
+            assert_eq!(class("status 503"), "ServerError");
+            assert_eq!(class("connection refused"), "NetworkError");
+            assert_eq!(class("processed 503 items"), "DianyaError");
+        });
+    }
+
+    #[test]
+    fn context_keeps_the_class() {
+        Python::initialize();
+        Python::attach(|py| {
+            let err = to_py_err_in(Error::InvalidInput("bad frame".into()), "frame 2 failed");
+            assert!(err.is_instance_of::<InvalidInputError>(py));
+            assert_eq!(err.value(py).to_string(), "frame 2 failed: bad frame");
+            assert!(err.cause(py).is_some());
+        });
+    }
+}
@@ -0,0 +1,733 @@
+use crate::{
+    errors::invalid_input,
+    py_types::{StatusResponse, UtteranceTranslationResponse},
+    types::{extract_utterances, normalize_name, parse_seconds},
+};
+use pyo3::prelude::*;
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    fs::File,
+    io::{self, BufWriter, Write as _},
+    sync::atomic::{AtomicU64, Ordering},
+};
+use transcribe::Utterance;
+
+/// Most SSML engines reject breaks longer than ten seconds.
+const MAX_SSML_BREAK_SECONDS: f64 = 10.0;
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
+
+pub fn render_ssml(
+    utterances: &[Utterance],
+    voice_map: &HashMap<i32, String>,
+    language: Option<&str>,
+    min_break_seconds: f64,
+) -> String {
+    let mut ssml =
+        String::from(r#"<speak version="1.0" xmlns="http://www.w3.org/2001/10/synthesis""#);
+    if let Some(language) = language {
+        let _ = write!(ssml, r#" xml:lang="{}""#, escape_xml(language));
+    }
+    ssml.push('>');
+
+    let mut previous_end: Option<f64> = None;
+    for utterance in utterances {
+        if let Some(end) = previous_end {
+            let gap = (utterance.start_time - end).min(MAX_SSML_BREAK_SECONDS);
+            if gap >= min_break_seconds && gap > 0.0 {
+                let _ = write!(
+                    ssml,
+                    r#"<break time="{}ms"/>"#,
+                    (gap * 1000.0).round() as u64
+                );
+            }
+        }
+        previous_end = Some(utterance.end_time);
+
+        let text = escape_xml(utterance.text.trim());
+        match voice_map.get(&utterance.speaker) {
+            Some(voice) => {
+                let _ = write!(
+                    ssml,
+                    r#"<voice name="{}">{text}</voice>"#,
+                    escape_xml(voice)
+                );
+            }
+            None => {
+                let _ = write!(ssml, "<s>{text}</s>");
+            }
+        }
+    }
+
+    ssml.push_str("</speak>");
+    ssml
+}
+
+pub const CSV_HEADER: &str = "start_time,end_time,speaker,text";
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn csv_row(utterance: &Utterance) -> String {
+    format!(
+        "{},{},{},{}",
+        utterance.start_time,
+        utterance.end_time,
+        utterance.speaker,
+        escape_csv(&utterance.text)
+    )
+}
+
+/// Writes one row per utterance through a buffered writer and returns the
+/// number of rows written, excluding the header.
+pub fn write_utterances_csv(path: &str, utterances: &[Utterance]) -> io::Result<usize> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    writeln!(writer, "{CSV_HEADER}")?;
+    for utterance in utterances {
+        writeln!(writer, "{}", csv_row(utterance))?;
+    }
+    writer.flush()?;
+    Ok(utterances.len())
+}
+
+/// Tells apart the temporary files of concurrent writes from this process.
+static PARTIAL_FILES: AtomicU64 = AtomicU64::new(0);
+
+/// Writes through a sibling `.part` file that is renamed into place, so a
+/// failed write never leaves a truncated file at `path`. Each call gets its
+/// own temporary name, so concurrent writes to one path cannot rename each
+/// other's partial data into place.
+pub async fn write_atomically(path: &str, data: &[u8]) -> io::Result<()> {
+    let partial = format!(
+        "{path}.{}-{}.part",
+        std::process::id(),
+        PARTIAL_FILES.fetch_add(1, Ordering::Relaxed)
+    );
+    let result = match tokio::fs::write(&partial, data).await {
+        Ok(()) => tokio::fs::rename(&partial, path).await,
+        Err(err) => Err(err),
+    };
+    if result.is_err() {
+        let _ = tokio::fs::remove_file(&partial).await;
+    }
+    result
+}
+
+#[pyfunction]
+#[pyo3(signature = (utterances, voice_map=None, *, language=None, min_break_seconds=0.3))]
+pub fn to_ssml(
+    utterances: Bound<'_, PyAny>,
+    voice_map: Option<HashMap<i32, String>>,
+    language: Option<String>,
+    min_break_seconds: f64,
+) -> PyResult<String> {
+    let utterances = extract_utterances(utterances)?;
+    parse_seconds(min_break_seconds, "min_break_seconds")?;
+    Ok(render_ssml(
+        &utterances,
+        &voice_map.unwrap_or_default(),
+        language.as_deref(),
+        min_break_seconds,
+    ))
+}
+
+/// `HH:MM:SS` plus `separator` and milliseconds, clamping negative times to
+/// zero.
+fn cue_timestamp(seconds: f64, separator: char) -> String {
+    let millis = (seconds.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}{separator}{:03}",
+        millis / 3_600_000,
+        millis / 60_000 % 60,
+        millis / 1000 % 60,
+        millis % 1000
+    )
+}
+
+/// `HH:MM:SS,mmm`.
+pub fn srt_timestamp(seconds: f64) -> String {
+    cue_timestamp(seconds, ',')
+}
+
+/// `HH:MM:SS.mmm`.
+pub fn vtt_timestamp(seconds: f64) -> String {
+    cue_timestamp(seconds, '.')
+}
+
+/// Cues pair utterances by position. When the counts differ, the unmatched
+/// tail still gets cues with only the line that exists. Pairs without any
+/// text get no cue, and the remaining cues are numbered consecutively.
+pub fn render_bilingual_srt(original: &[Utterance], translated: &[Utterance]) -> String {
+    let mut srt = String::new();
+    let mut cue = 0;
+    for index in 0..original.len().max(translated.len()) {
+        let source = original.get(index);
+        let target = translated.get(index);
+        let Some(timing) = source.or(target) else {
+            break;
+        };
+        let lines: Vec<&str> = [source, target]
+            .into_iter()
+            .flatten()
+            .map(|utterance| utterance.text.trim())
+            .filter(|text| !text.is_empty())
+            .collect();
+        if lines.is_empty() {
+            continue;
+        }
+
+        cue += 1;
+        let _ = writeln!(srt, "{cue}");
+        let _ = writeln!(
+            srt,
+            "{} --> {}",
+            srt_timestamp(timing.start_time),
+            srt_timestamp(timing.end_time)
+        );
+        for line in lines {
+            let _ = writeln!(srt, "{line}");
+        }
+        srt.push('\n');
+    }
+    srt
+}
+
+pub fn render_srt(utterances: &[Utterance]) -> String {
+    render_bilingual_srt(utterances, &[])
+}
+
+/// Cue text may only escape `&`, `<` and `>`.
+fn escape_vtt(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// One cue per utterance with text, voiced by its speaker.
+pub fn render_vtt(utterances: &[Utterance]) -> String {
+    let mut vtt = String::from("WEBVTT\n\n");
+    for utterance in utterances {
+        if utterance.text.trim().is_empty() {
+            continue;
+        }
+        let _ = writeln!(
+            vtt,
+            "{} --> {}",
+            vtt_timestamp(utterance.start_time),
+            vtt_timestamp(utterance.end_time)
+        );
+        let _ = writeln!(
+            vtt,
+            "<v Speaker {}>{}",
+            utterance.speaker,
+            escape_vtt(utterance.text.trim())
+        );
+        vtt.push('\n');
+    }
+    vtt
+}
+
+#[pyfunction]
+pub fn to_bilingual_srt(
+    original: Bound<'_, PyAny>,
+    translation: PyRef<'_, UtteranceTranslationResponse>,
+) -> PyResult<String> {
+    let original = extract_utterances(original)?;
+    let translated: Vec<Utterance> = translation.details().iter().map(Utterance::from).collect();
+    Ok(render_bilingual_srt(&original, &translated))
+}
+
+/// Splits at the first `delimiter`, so a translation that itself contains the
+/// delimiter stays whole. Without a delimiter (or with nothing after it) the
+/// whole text is treated as the original.
+pub fn split_combined<'a>(text: &'a str, delimiter: &str) -> (&'a str, Option<&'a str>) {
+    match text.split_once(delimiter) {
+        Some((original, translated)) if !translated.trim().is_empty() => {
+            (original.trim(), Some(translated.trim()))
+        }
+        Some((original, _)) => (original.trim(), None),
+        None => (text.trim(), None),
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (text, *, delimiter="\n"))]
+pub fn split_bilingual(text: &str, delimiter: &str) -> PyResult<(String, Option<String>)> {
+    if delimiter.is_empty() {
+        return Err(invalid_input("delimiter must not be empty"));
+    }
+    let (original, translated) = split_combined(text, delimiter);
+    Ok((original.to_string(), translated.map(str::to_string)))
+}
+
+/// Speaker prefixes used by text exports, e.g. `说话人1` or `Speaker 1`.
+const SPEAKER_PREFIXES: [&str; 3] = ["speaker", "说话人", "发言人"];
+
+/// Replaces a numeric speaker label at the start of `line`, if the map has a
+/// name for it. Only line starts are considered so spoken text is untouched.
+fn relabel_line(line: &str, labels: &HashMap<i32, String>) -> Option<String> {
+    let body = line.trim_start();
+    let indent = &line[..line.len() - body.len()];
+    let prefix = SPEAKER_PREFIXES.iter().find(|prefix| {
+        body.get(..prefix.len())
+            .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+    })?;
+
+    let after_prefix = &body[prefix.len()..];
+    let number_start = after_prefix.trim_start_matches(' ');
+    let digits = number_start
+        .find(|ch: char| !ch.is_ascii_digit())
+        .unwrap_or(number_start.len());
+    let speaker: i32 = number_start[..digits].parse().ok()?;
+    let name = labels.get(&speaker)?;
+    Some(format!("{indent}{name}{}", &number_start[digits..]))
+}
+
+pub fn relabel_speakers(text: &str, labels: &HashMap<i32, String>) -> String {
+    text.split_inclusive('\n')
+        .map(|line| relabel_line(line, labels).unwrap_or_else(|| line.to_string()))
+        .collect()
+}
+
+/// Rejects exports that are empty or cut short. PDFs must start with `%PDF-`
+/// and end with an `%%EOF` marker; DOCX files are ZIP archives, so they must
+/// start with a local file header and contain the end-of-central-directory
+/// record near the end. `format` is a name as `normalize_name` leaves it.
+pub fn check_export(data: &[u8], format: &str) -> Result<(), String> {
+    if data.is_empty() {
+        return Err(format!("{format} export is empty"));
+    }
+    let tail = &data[data.len().saturating_sub(64 * 1024 + 22)..];
+    let contains = |haystack: &[u8], needle: &[u8]| {
+        haystack
+            .windows(needle.len())
+            .any(|window| window == needle)
+    };
+    match format {
+        "pdf" if !data.starts_with(b"%PDF-") => Err("pdf export does not start with %PDF-".into()),
+        "pdf" if !contains(&tail[tail.len().saturating_sub(1024)..], b"%%EOF") => Err(format!(
+            "pdf export of {} bytes is truncated (no %%EOF)",
+            data.len()
+        )),
+        "docx" if !data.starts_with(b"PK\x03\x04") => {
+            Err("docx export is not a ZIP archive".into())
+        }
+        "docx" if !contains(tail, b"PK\x05\x06") => Err(format!(
+            "docx export of {} bytes is truncated (no end of central directory)",
+            data.len()
+        )),
+        "txt" => std::str::from_utf8(data)
+            .map(|_| ())
+            .map_err(|err| format!("txt export is not valid UTF-8: {err}")),
+        _ => Ok(()),
+    }
+}
+
+#[pyfunction]
+pub fn validate_export_bytes(data: Vec<u8>, format: &str) -> PyResult<()> {
+    check_export(&data, &normalize_name(format)).map_err(invalid_input)
+}
+
+/// Overview, summary and keywords under their own headings. Sections that are
+/// missing or blank are left out entirely.
+pub fn render_report_markdown(
+    title: Option<&str>,
+    overview: Option<&str>,
+    summary: Option<&str>,
+    keywords: &[String],
+) -> String {
+    let mut sections = Vec::new();
+    if let Some(title) = title {
+        sections.push(format!("# {}", title.trim()));
+    }
+    for (heading, body) in [("Overview", overview), ("Summary", summary)] {
+        if let Some(body) = body.map(str::trim).filter(|body| !body.is_empty()) {
+            sections.push(format!("## {heading}\n\n{body}"));
+        }
+    }
+    let keywords: Vec<String> = keywords
+        .iter()
+        .map(|keyword| keyword.trim())
+        .filter(|keyword| !keyword.is_empty())
+        .map(|keyword| format!("- {keyword}"))
+        .collect();
+    if !keywords.is_empty() {
+        sections.push(format!("## Keywords\n\n{}", keywords.join("\n")));
+    }
+
+    let mut markdown = sections.join("\n\n");
+    if !markdown.is_empty() {
+        markdown.push('\n');
+    }
+    markdown
+}
+
+#[pyfunction]
+#[pyo3(signature = (status, *, title=None))]
+pub fn to_report_markdown(status: PyRef<'_, StatusResponse>, title: Option<&str>) -> String {
+    render_report_markdown(
+        title,
+        status.overview_md(),
+        status.summary_md(),
+        status.keywords(),
+    )
+}
+
+fn is_cue_timing(line: &str) -> bool {
+    line.contains("-->")
+}
+
+fn strip_tags(line: &str) -> String {
+    let mut text = String::with_capacity(line.len());
+    let mut in_tag = false;
+    for ch in line.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            other if !in_tag => text.push(other),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Spoken text of an export, without cue numbers, timings or markup.
+pub fn plain_text(data: &[u8], format: &str) -> PyResult<String> {
+    let text = std::str::from_utf8(data)
+        .map_err(|err| invalid_input(format!("{format} export is not valid UTF-8: {err}")))?;
+    let text = text.trim_start_matches('\u{feff}');
+
+    let lines: Vec<String> = match format.to_ascii_lowercase().as_str() {
+        "txt" => text.lines().map(str::to_string).collect(),
+        "srt" => text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !is_cue_timing(line))
+            .filter(|line| !line.chars().all(|ch| ch.is_ascii_digit()))
+            .map(strip_tags)
+            .collect(),
+        "vtt" => {
+            let mut lines = Vec::new();
+            let mut in_note = false;
+            for line in text.lines().map(str::trim) {
+                if line.is_empty() {
+                    in_note = false;
+                } else if line.starts_with("WEBVTT") || line.starts_with("NOTE") {
+                    in_note = true;
+                } else if !in_note && !is_cue_timing(line) {
+                    lines.push(strip_tags(line));
+                }
+            }
+            lines
+        }
+        other => {
+            return Err(invalid_input(format!(
+                "cannot extract text from '{other}' exports (expected 'txt', 'srt' or 'vtt')"
+            )));
+        }
+    };
+
+    Ok(lines
+        .iter()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+/// Lowercased letters and digits only, so layout and punctuation differences
+/// between formats do not count as mismatches.
+fn normalize_text(text: &str) -> String {
+    text.chars()
+        .filter(|ch| ch.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[pyfunction]
+pub fn export_plain_text(data: Vec<u8>, format: &str) -> PyResult<String> {
+    plain_text(&data, format)
+}
+
+#[pyfunction]
+pub fn export_texts_match(
+    left: Vec<u8>,
+    left_format: &str,
+    right: Vec<u8>,
+    right_format: &str,
+) -> PyResult<bool> {
+    let left = normalize_text(&plain_text(&left, left_format)?);
+    let right = normalize_text(&plain_text(&right, right_format)?);
+    Ok(left == right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utterance(start_time: f64, end_time: f64, text: &str, speaker: i32) -> Utterance {
+        Utterance {
+            start_time,
+            end_time,
+            text: text.to_string(),
+            speaker,
+        }
+    }
+
+    #[test]
+    fn srt_timestamps_round_and_clamp() {
+        assert_eq!(srt_timestamp(3723.4567), "01:02:03,457");
+        assert_eq!(srt_timestamp(-1.0), "00:00:00,000");
+    }
+
+    #[test]
+    fn render_srt_numbers_cues_and_trims_text() {
+        let utterances = [
+            utterance(0.0, 1.5, " 你好 ", 1),
+            utterance(1.5, 3.0, "world", 2),
+        ];
+        assert_eq!(
+            render_srt(&utterances),
+            "1\n00:00:00,000 --> 00:00:01,500\n你好\n\n\
+             2\n00:00:01,500 --> 00:00:03,000\nworld\n\n"
+        );
+    }
+
+    #[test]
+    fn render_vtt_voices_speakers_and_escapes_text() {
+        let utterances = [utterance(61.0, 62.25, "a < b & c", 3)];
+        assert_eq!(
+            render_vtt(&utterances),
+            "WEBVTT\n\n00:01:01.000 --> 00:01:02.250\n<v Speaker 3>a &lt; b &amp; c\n\n"
+        );
+    }
+
+    /// A fresh directory under the system temp dir for one test.
+    fn scratch_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("dianyaapi-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[tokio::test]
+    async fn write_atomically_replaces_the_file() {
+        let dir = scratch_dir("replace");
+        let path = dir.join("export.txt");
+        std::fs::write(&path, "old").unwrap();
+        write_atomically(path.to_str().unwrap(), b"new")
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn failed_write_leaves_the_target_untouched() {
+        let dir = scratch_dir("failed");
+        // A non-empty directory cannot be replaced by a file, so the rename
+        // fails after the partial file was written.
+        let path = dir.join("export.txt");
+        std::fs::create_dir(&path).unwrap();
+        std::fs::write(path.join("kept"), "kept").unwrap();
+        assert!(
+            write_atomically(path.to_str().unwrap(), b"new")
+                .await
+                .is_err()
+        );
+        assert_eq!(std::fs::read_to_string(path.join("kept")).unwrap(), "kept");
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn concurrent_writes_never_mix() {
+        let dir = scratch_dir("concurrent");
+        let path = dir.join("export.txt");
+        let path = path.to_str().unwrap();
+        let first = vec![b'a'; 1 << 20];
+        let second = vec![b'b'; 1 << 20];
+        let (a, b) = tokio::join!(
+            write_atomically(path, &first),
+            write_atomically(path, &second)
+        );
+        a.unwrap();
+        b.unwrap();
+        let written = std::fs::read(path).unwrap();
+        assert!(written == first || written == second);
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn check_export_accepts_complete_files() {
+        assert_eq!(check_export(b"%PDF-1.7\n...\n%%EOF\n", "pdf"), Ok(()));
+        assert_eq!(check_export(b"PK\x03\x04...PK\x05\x06", "docx"), Ok(()));
+        assert_eq!(
+            check_export("说话人1 00:00\n你好".as_bytes(), "txt"),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn check_export_rejects_damaged_files() {
+        assert!(check_export(b"", "txt").is_err());
+        assert!(check_export(b"<html>", "pdf").is_err());
+        assert!(check_export(b"%PDF-1.7\n...", "pdf").is_err());
+        assert!(check_export(b"%PDF-1.7", "docx").is_err());
+        assert!(check_export(b"PK\x03\x04...", "docx").is_err());
+        assert!(check_export(b"\xff\xfe", "txt").is_err());
+    }
+
+    #[test]
+    fn split_combined_separates_original_and_translation() {
+        assert_eq!(split_combined("你好\nHello", "\n"), ("你好", Some("Hello")));
+        assert_eq!(
+            split_combined(" 你好 | Hello ", "|"),
+            ("你好", Some("Hello"))
+        );
+        assert_eq!(split_combined("a\nb\nc", "\n"), ("a", Some("b\nc")));
+    }
+
+    #[test]
+    fn split_combined_without_translation() {
+        assert_eq!(split_combined("你好", "\n"), ("你好", None));
+        assert_eq!(split_combined("你好\n  ", "\n"), ("你好", None));
+        assert_eq!(split_combined("", "\n"), ("", None));
+    }
+
+    #[test]
+    fn split_bilingual_rejects_an_empty_delimiter() {
+        Python::initialize();
+        assert!(split_bilingual("你好\nHello", "").is_err());
+        assert_eq!(
+            split_bilingual("你好\nHello", "\n").unwrap(),
+            ("你好".to_string(), Some("Hello".to_string()))
+        );
+    }
+
+    #[test]
+    fn empty_transcripts_render_empty_files() {
+        assert_eq!(render_srt(&[]), "");
+        assert_eq!(render_vtt(&[]), "WEBVTT\n\n");
+    }
+
+    #[test]
+    fn blank_utterances_get_no_cue() {
+        let utterances = [utterance(0.0, 1.0, "  ", 0), utterance(1.0, 2.0, "你好", 1)];
+        assert_eq!(
+            render_srt(&utterances),
+            "1\n00:00:01,000 --> 00:00:02,000\n你好\n\n"
+        );
+        assert_eq!(
+            render_vtt(&utterances),
+            "WEBVTT\n\n00:00:01.000 --> 00:00:02.000\n<v Speaker 1>你好\n\n"
+        );
+    }
+
+    #[test]
+    fn report_markdown_orders_present_sections() {
+        let keywords = ["会议".to_string(), " ".to_string(), "预算".to_string()];
+        let markdown = render_report_markdown(
+            Some(" 周会 "),
+            Some("两位发言人讨论了预算。"),
+            Some("- 预算下调\n"),
+            &keywords,
+        );
+        assert_eq!(
+            markdown,
+            "# 周会\n\n## Overview\n\n两位发言人讨论了预算。\n\n## Summary\n\n- 预算下调\n\n\
+             ## Keywords\n\n- 会议\n- 预算\n"
+        );
+    }
+
+    #[test]
+    fn report_markdown_skips_missing_sections() {
+        let markdown = render_report_markdown(None, Some("  "), Some("摘要"), &[]);
+        assert_eq!(markdown, "## Summary\n\n摘要\n");
+        assert_eq!(render_report_markdown(None, None, None, &[]), "");
+    }
+
+    #[test]
+    fn ssml_voices_speakers_and_breaks_on_gaps() {
+        let utterances = [
+            utterance(0.0, 1.0, "你好", 0),
+            utterance(1.1, 2.0, "hi", 1),
+            utterance(3.5, 4.0, "再见", 0),
+        ];
+        let voices = HashMap::from([(0, "zh-CN-Xiaoxiao".to_string())]);
+        let expected = concat!(
+            r#"<speak version="1.0" xmlns="http://www.w3.org/2001/10/synthesis""#,
+            r#" xml:lang="zh-CN">"#,
+            r#"<voice name="zh-CN-Xiaoxiao">你好</voice><s>hi</s><break time="1500ms"/>"#,
+            r#"<voice name="zh-CN-Xiaoxiao">再见</voice></speak>"#,
+        );
+        assert_eq!(
+            render_ssml(&utterances, &voices, Some("zh-CN"), 0.3),
+            expected
+        );
+    }
+
+    #[test]
+    fn ssml_escapes_text_and_attributes() {
+        let utterances = [utterance(0.0, 1.0, r#"a < b & "c" 'd'"#, 0)];
+        let voices = HashMap::from([(0, r#"x"y"#.to_string())]);
+        let ssml = render_ssml(&utterances, &voices, None, 0.3);
+        assert!(ssml.contains(r#"<voice name="x&quot;y">"#));
+        assert!(ssml.contains("a &lt; b &amp; &quot;c&quot; &apos;d&apos;</voice>"));
+    }
+
+    #[test]
+    fn ssml_caps_long_breaks() {
+        let utterances = [utterance(0.0, 1.0, "a", 0), utterance(60.0, 61.0, "b", 0)];
+        let ssml = render_ssml(&utterances, &HashMap::new(), None, 0.3);
+        assert!(ssml.contains(r#"<break time="10000ms"/>"#));
+        assert!(ssml.ends_with(r#"<s>a</s><break time="10000ms"/><s>b</s></speak>"#));
+    }
+
+    #[test]
+    fn relabel_speakers_replaces_known_line_prefixes() {
+        let labels = HashMap::from([(1, "张三".to_string()), (2, "Bob".to_string())]);
+        let text = "说话人1：你好\n  Speaker 2: hi speaker 1\nSpeaker 3: ok\n提到说话人1";
+        assert_eq!(
+            relabel_speakers(text, &labels),
+            "张三：你好\n  Bob: hi speaker 1\nSpeaker 3: ok\n提到说话人1"
+        );
+    }
+
+    #[test]
+    fn export_texts_match_ignores_layout_and_punctuation() {
+        let txt = "你好，世界。\nHello world!\n";
+        let srt = "1\n00:00:00,000 --> 00:00:01,000\n你好世界\n\n\
+                   2\n00:00:01,000 --> 00:00:02,000\n<i>hello</i> World\n\n";
+        assert_eq!(
+            plain_text(srt.as_bytes(), "srt").unwrap(),
+            "你好世界\nhello World"
+        );
+        assert!(export_texts_match(txt.into(), "txt", srt.into(), "srt").unwrap());
+
+        let vtt = "WEBVTT\n\n00:00:00.000 --> 00:00:02.000\n<v Speaker 0>你好\n\n";
+        assert_eq!(plain_text(vtt.as_bytes(), "vtt").unwrap(), "你好");
+        assert!(!export_texts_match(txt.into(), "txt", vtt.into(), "vtt").unwrap());
+    }
+}
@@ -1,18 +1,79 @@
+mod audio;
+mod capture;
+mod dict;
+mod errors;
+mod formats;
+mod logging;
+mod pickle;
 mod py_types;
+mod quota;
+mod repr;
+mod retry;
+mod stream_message;
 mod transcribe_stream;
 mod transcribe_wrapper;
 mod types;
+mod utterances;
 
 use crate::transcribe_stream::TranscribeStream;
 use crate::transcribe_wrapper::TranscribeApi;
 use pyo3::{
     Bound, PyResult, Python, pymodule,
-    types::{PyModule, PyModuleMethods},
+    types::{PyAnyMethods, PyModule, PyModuleMethods},
+    wrap_pyfunction,
 };
 
 #[pymodule]
-pub fn dianyaapi(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+pub fn dianyaapi(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    logging::install();
     m.add_class::<TranscribeApi>()?;
     m.add_class::<TranscribeStream>()?;
+    m.add_class::<quota::QuotaTracker>()?;
+    m.add_class::<retry::RetryConfig>()?;
+    // Response classes are only built from Rust, but `pickle` looks them up
+    // by name in the module.
+    m.add_class::<py_types::SessionCreateResult>()?;
+    m.add_class::<py_types::SessionCloseResult>()?;
+    m.add_class::<py_types::StreamStats>()?;
+    m.add_class::<py_types::StreamMessage>()?;
+    m.add_class::<py_types::ModelRecommendation>()?;
+    m.add_class::<py_types::CoverageReport>()?;
+    m.add_class::<py_types::UploadResult>()?;
+    m.add_class::<py_types::UploadOutcome>()?;
+    m.add_class::<py_types::UtterancePayload>()?;
+    m.add_class::<py_types::SummaryContent>()?;
+    m.add_class::<py_types::CallbackHistoryItem>()?;
+    m.add_class::<py_types::StatusResponse>()?;
+    m.add_class::<py_types::CallbackResponse>()?;
+    m.add_class::<py_types::ShareLinkResponse>()?;
+    m.add_class::<py_types::SummaryCreateResponse>()?;
+    m.add_class::<py_types::ChunkedSummaryResponse>()?;
+    m.add_class::<py_types::TextTranslationResponse>()?;
+    m.add_class::<py_types::UtteranceTranslationResponse>()?;
+    m.add_class::<py_types::TranslationDetail>()?;
+    m.add_class::<py_types::TranscribeTranslationResponse>()?;
+    m.add_class::<py_types::TranslatedSummaryResponse>()?;
+    errors::register(py, m)?;
+    m.add_function(wrap_pyfunction!(audio::check_session_duration, m)?)?;
+    m.add_function(wrap_pyfunction!(audio::recommend_model, m)?)?;
+    m.add_function(wrap_pyfunction!(formats::to_ssml, m)?)?;
+    m.add_function(wrap_pyfunction!(formats::to_bilingual_srt, m)?)?;
+    m.add_function(wrap_pyfunction!(formats::split_bilingual, m)?)?;
+    m.add_function(wrap_pyfunction!(formats::to_report_markdown, m)?)?;
+    m.add_function(wrap_pyfunction!(formats::export_plain_text, m)?)?;
+    m.add_function(wrap_pyfunction!(formats::validate_export_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(formats::export_texts_match, m)?)?;
+    m.add_function(wrap_pyfunction!(utterances::validate_utterances, m)?)?;
+    m.add_function(wrap_pyfunction!(utterances::transcript_coverage, m)?)?;
+    m.add_function(wrap_pyfunction!(utterances::merge_one_sentence_results, m)?)?;
+    m.add_function(wrap_pyfunction!(utterances::split_sentences, m)?)?;
+    m.add_function(wrap_pyfunction!(py_types::new_callback_attempts, m)?)?;
+    m.add_function(wrap_pyfunction!(py_types::changed_translations, m)?)?;
+    m.add_function(wrap_pyfunction!(types::set_lenient_numbers, m)?)?;
+    m.add_function(wrap_pyfunction!(types::unique_task_ids, m)?)?;
+    m.add_function(wrap_pyfunction!(types::language_from_native_name, m)?)?;
+    m.add_function(wrap_pyfunction!(types::supported_languages, m)?)?;
+    m.add_function(wrap_pyfunction!(types::supported_models, m)?)?;
+    m.add_function(wrap_pyfunction!(logging::set_log_level, m)?)?;
     Ok(())
 }
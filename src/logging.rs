@@ -0,0 +1,105 @@
+use crate::errors::invalid_input;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use pyo3::prelude::*;
+
+const LOGGER_NAME: &str = "dianyaapi";
+/// Forwarded until `set_log_level` says otherwise.
+const DEFAULT_LEVEL: LevelFilter = LevelFilter::Warn;
+
+/// Forwards this crate's `log` records to Python's `logging`, each to a child
+/// of the `dianyaapi` logger named after its module. Records of dependencies
+/// (tungstenite, rustls, hyper, ...) are dropped before the GIL is taken.
+struct PythonLogger;
+
+static LOGGER: PythonLogger = PythonLogger;
+
+fn python_level(level: Level) -> u8 {
+    match level {
+        Level::Error => 40,
+        Level::Warn => 30,
+        Level::Info => 20,
+        Level::Debug => 10,
+        // `logging` has no trace level; 5 sits below DEBUG.
+        Level::Trace => 5,
+    }
+}
+
+fn is_own_target(target: &str) -> bool {
+    target
+        .strip_prefix(LOGGER_NAME)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+}
+
+impl Log for PythonLogger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= log::max_level() && is_own_target(metadata.target())
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let name = record.target().replace("::", ".");
+        let level = python_level(record.level());
+        let message = record.args().to_string();
+        Python::attach(|py| {
+            // Handler failures are `logging`'s to report; they must not
+            // surface in whatever code happened to log.
+            let _ = py
+                .import("logging")
+                .and_then(|logging| logging.call_method1("getLogger", (name,)))
+                .and_then(|logger| logger.call_method1("log", (level, message)));
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs the bridge unless another extension in the process already set
+/// a `log` logger, in which case records go there instead.
+pub fn install() {
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(DEFAULT_LEVEL);
+    }
+}
+
+/// Also sets the `dianyaapi` Python logger to the same level, so one call is
+/// enough to see the records; `"off"` only stops forwarding.
+#[pyfunction]
+pub fn set_log_level(py: Python<'_>, level: &str) -> PyResult<()> {
+    let filter = match level.to_ascii_lowercase().as_str() {
+        "off" => LevelFilter::Off,
+        "error" => LevelFilter::Error,
+        "warning" | "warn" => LevelFilter::Warn,
+        "info" => LevelFilter::Info,
+        "debug" => LevelFilter::Debug,
+        "trace" => LevelFilter::Trace,
+        _ => {
+            return Err(invalid_input(format!(
+                "unsupported log level '{level}' \
+                 (expected 'off', 'error', 'warning', 'info', 'debug' or 'trace')"
+            )));
+        }
+    };
+    log::set_max_level(filter);
+    if let Some(level) = filter.to_level() {
+        py.import("logging")?
+            .call_method1("getLogger", (LOGGER_NAME,))?
+            .call_method1("setLevel", (python_level(level),))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_own_targets_are_forwarded() {
+        assert!(is_own_target("dianyaapi"));
+        assert!(is_own_target("dianyaapi::transcribe_stream"));
+        assert!(!is_own_target("dianyaapi_sdk::client"));
+        assert!(!is_own_target("tungstenite::protocol"));
+        assert!(!is_own_target("rustls::conn"));
+    }
+}
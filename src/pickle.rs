@@ -0,0 +1,88 @@
+use crate::errors::invalid_input;
+use pyo3::{
+    IntoPyObjectExt,
+    prelude::*,
+    types::{PyDict, PyList},
+};
+use std::{collections::HashMap, hash::Hash};
+
+/// Conversion of a field value to and from the state `pickle` stores. Nested
+/// response classes stay Python objects, so they are pickled through their
+/// own `__reduce__`.
+pub trait StateValue: Sized {
+    fn state_value<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>>;
+    fn from_state(value: &Bound<'_, PyAny>) -> PyResult<Self>;
+}
+
+macro_rules! plain_state_value {
+    ($($ty:ty),*) => {
+        $(impl StateValue for $ty {
+            fn state_value<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+                self.clone().into_bound_py_any(py)
+            }
+
+            fn from_state(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+                value.extract()
+            }
+        })*
+    };
+}
+
+plain_state_value!(String, bool, i32, u32, u64, f64);
+
+impl<T: StateValue> StateValue for Option<T> {
+    fn state_value<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            Some(value) => value.state_value(py),
+            None => Ok(py.None().into_bound(py)),
+        }
+    }
+
+    fn from_state(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if value.is_none() {
+            return Ok(None);
+        }
+        T::from_state(value).map(Some)
+    }
+}
+
+impl<T: StateValue> StateValue for Vec<T> {
+    fn state_value<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let items = self
+            .iter()
+            .map(|item| item.state_value(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new(py, items)?.into_any())
+    }
+
+    fn from_state(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        value
+            .try_iter()?
+            .map(|item| T::from_state(&item?))
+            .collect()
+    }
+}
+
+impl<K: StateValue + Eq + Hash, V: StateValue> StateValue for HashMap<K, V> {
+    fn state_value<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let dict = PyDict::new(py);
+        for (key, value) in self {
+            dict.set_item(key.state_value(py)?, value.state_value(py)?)?;
+        }
+        Ok(dict.into_any())
+    }
+
+    fn from_state(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        value
+            .cast::<PyDict>()?
+            .iter()
+            .map(|(key, value)| Ok((K::from_state(&key)?, V::from_state(&value)?)))
+            .collect()
+    }
+}
+
+/// Error for a pickled state that does not fit the class it is loaded into,
+/// e.g. one written by a version with different fields.
+pub fn invalid_state(class: &str) -> PyErr {
+    invalid_input(format!("pickled {class} state does not match its fields"))
+}
@@ -1,6 +1,20 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    hash::{DefaultHasher, Hash, Hasher},
+};
 
-use pyo3::prelude::*;
+use crate::{
+    dict::{DictValue, FromDictValue, dict_field, mismatched},
+    pickle::{StateValue, invalid_state},
+    repr::{Repr, ReprValue},
+    stream_message::parse_message,
+    types::Glossary,
+};
+use pyo3::{
+    exceptions::PyIndexError,
+    prelude::*,
+    types::{PyDict, PyIterator, PyList, PyMapping, PyTuple},
+};
 use transcribe::{
     Utterance,
     transcribe::{
@@ -68,6 +82,81 @@ impl From<SessionEnder> for SessionCloseResult {
     }
 }
 
+#[pyclass(module = "dianyaapi")]
+#[derive(Clone, Debug)]
+pub struct StreamStats {
+    #[pyo3(get)]
+    pub(crate) frames_sent: u64,
+    #[pyo3(get)]
+    pub(crate) bytes_sent: u64,
+    #[pyo3(get)]
+    pub(crate) messages_received: u64,
+    #[pyo3(get)]
+    pub(crate) bytes_received: u64,
+    #[pyo3(get)]
+    pub(crate) reconnects: u64,
+    #[pyo3(get)]
+    pub(crate) elapsed_seconds: f64,
+}
+
+/// One streaming frame, decoded as far as its shape allows.
+#[pyclass(module = "dianyaapi")]
+#[derive(Clone, Debug)]
+pub struct StreamMessage {
+    #[pyo3(get)]
+    message_type: String,
+    #[pyo3(get)]
+    is_final: bool,
+    #[pyo3(get)]
+    utterances: Vec<UtterancePayload>,
+    #[pyo3(get)]
+    raw: String,
+}
+
+impl From<String> for StreamMessage {
+    /// Frames that are not JSON objects or carry no type key become
+    /// `"unknown"` messages rather than errors.
+    fn from(raw: String) -> Self {
+        let parsed = parse_message(&raw);
+        Self {
+            message_type: parsed.message_type.unwrap_or_else(|| "unknown".to_string()),
+            is_final: parsed.is_final,
+            utterances: parsed
+                .utterances
+                .into_iter()
+                .map(UtterancePayload::from)
+                .collect(),
+            raw,
+        }
+    }
+}
+
+#[pyclass(module = "dianyaapi")]
+#[derive(Clone, Debug)]
+pub struct ModelRecommendation {
+    #[pyo3(get)]
+    pub(crate) model: String,
+    #[pyo3(get)]
+    pub(crate) short_asr: bool,
+    #[pyo3(get)]
+    pub(crate) transcribe_only: bool,
+    #[pyo3(get)]
+    pub(crate) duration: f64,
+}
+
+#[pyclass(module = "dianyaapi")]
+#[derive(Clone, Debug)]
+pub struct CoverageReport {
+    #[pyo3(get)]
+    pub(crate) covered_seconds: f64,
+    #[pyo3(get)]
+    pub(crate) audio_seconds: f64,
+    #[pyo3(get)]
+    pub(crate) fraction: f64,
+    #[pyo3(get)]
+    pub(crate) likely_truncated: bool,
+}
+
 #[pyclass(module = "dianyaapi")]
 #[derive(Clone, Debug)]
 pub struct UploadResult {
@@ -81,6 +170,8 @@ pub struct UploadResult {
     message: Option<String>,
     #[pyo3(get)]
     data: Option<String>,
+    #[pyo3(get)]
+    model: Option<String>,
 }
 
 impl From<UploadResponse> for UploadResult {
@@ -92,6 +183,7 @@ impl From<UploadResponse> for UploadResult {
                 status: None,
                 message: None,
                 data: None,
+                model: None,
             },
             UploadResponse::OneSentence(one_sentence) => Self {
                 kind: "one_sentence".into(),
@@ -99,21 +191,64 @@ impl From<UploadResponse> for UploadResult {
                 status: Some(one_sentence.status),
                 message: Some(one_sentence.message),
                 data: Some(one_sentence.data),
+                model: None,
             },
         }
     }
 }
 
+impl UploadResult {
+    pub(crate) fn with_model(mut self, model: &str) -> Self {
+        self.model = Some(model.to_string());
+        self
+    }
+
+    /// Recognized text of a one-sentence upload; `None` for normal tasks.
+    pub(crate) fn one_sentence_text(&self) -> Option<&str> {
+        match self.kind.as_str() {
+            "one_sentence" => self.data.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// One file of a batch upload: its `result` on success, otherwise the
+/// `error` message.
 #[pyclass(module = "dianyaapi")]
 #[derive(Clone, Debug)]
-pub struct UtterancePayload {
+pub struct UploadOutcome {
     #[pyo3(get)]
-    start_time: f64,
+    filepath: String,
     #[pyo3(get)]
-    end_time: f64,
+    result: Option<UploadResult>,
     #[pyo3(get)]
+    error: Option<String>,
+}
+
+impl UploadOutcome {
+    pub(crate) fn new(filepath: String, result: PyResult<UploadResult>) -> Self {
+        let (result, error) = match result {
+            Ok(result) => (Some(result), None),
+            Err(err) => (None, Some(err.to_string())),
+        };
+        Self {
+            filepath,
+            result,
+            error,
+        }
+    }
+}
+
+#[pyclass(module = "dianyaapi")]
+#[derive(Clone, Debug)]
+pub struct UtterancePayload {
+    #[pyo3(get, set)]
+    start_time: f64,
+    #[pyo3(get, set)]
+    end_time: f64,
+    #[pyo3(get, set)]
     text: String,
-    #[pyo3(get)]
+    #[pyo3(get, set)]
     speaker: i32,
 }
 
@@ -139,7 +274,19 @@ impl From<&Utterance> for UtterancePayload {
     }
 }
 
-#[pyclass(module = "dianyaapi")]
+impl From<&UtterancePayload> for Utterance {
+    fn from(value: &UtterancePayload) -> Self {
+        Self {
+            start_time: value.start_time,
+            end_time: value.end_time,
+            text: value.text.clone(),
+            speaker: value.speaker,
+        }
+    }
+}
+
+// Frozen, as `__hash__` must not change over the lifetime of an instance.
+#[pyclass(module = "dianyaapi", frozen)]
 #[derive(Clone, Debug)]
 pub struct SummaryContent {
     #[pyo3(get)]
@@ -163,7 +310,8 @@ impl From<InnerSummaryContent> for SummaryContent {
     }
 }
 
-#[pyclass(module = "dianyaapi")]
+// Frozen, as `__hash__` must not change over the lifetime of an instance.
+#[pyclass(module = "dianyaapi", frozen)]
 #[derive(Clone, Debug)]
 pub struct CallbackHistoryItem {
     #[pyo3(get)]
@@ -184,16 +332,31 @@ impl From<CallbackHistory> for CallbackHistoryItem {
     }
 }
 
+/// Entries of `later` whose timestamp does not appear in `earlier`, in the
+/// order the server listed them.
+#[pyfunction]
+pub fn new_callback_attempts(
+    earlier: Vec<PyRef<'_, CallbackHistoryItem>>,
+    later: Vec<PyRef<'_, CallbackHistoryItem>>,
+) -> Vec<CallbackHistoryItem> {
+    let seen: HashSet<&str> = earlier.iter().map(|item| item.timestamp.as_str()).collect();
+    later
+        .iter()
+        .filter(|item| !seen.contains(item.timestamp.as_str()))
+        .map(|item| (**item).clone())
+        .collect()
+}
+
 #[pyclass(module = "dianyaapi")]
 #[derive(Clone, Debug)]
 pub struct StatusResponse {
     #[pyo3(get)]
     status: String,
-    #[pyo3(get)]
+    #[pyo3(get, set)]
     overview_md: Option<String>,
-    #[pyo3(get)]
+    #[pyo3(get, set)]
     summary_md: Option<String>,
-    #[pyo3(get)]
+    #[pyo3(get, set)]
     details: Vec<UtterancePayload>,
     #[pyo3(get)]
     message: Option<String>,
@@ -201,12 +364,14 @@ pub struct StatusResponse {
     usage_id: Option<String>,
     #[pyo3(get)]
     task_id: Option<String>,
-    #[pyo3(get)]
+    #[pyo3(get, set)]
     keywords: Vec<String>,
     #[pyo3(get)]
     callback_history: Vec<CallbackHistoryItem>,
     #[pyo3(get)]
     task_type: Option<String>,
+    #[pyo3(get)]
+    speaker_map: Option<HashMap<i32, i32>>,
 }
 
 impl From<TranscribeStatus> for StatusResponse {
@@ -225,7 +390,41 @@ impl From<TranscribeStatus> for StatusResponse {
                 .task_type
                 .map(status_task_type_to_str)
                 .map(str::to_string),
+            speaker_map: None,
+        }
+    }
+}
+
+impl StatusResponse {
+    pub(crate) fn overview_md(&self) -> Option<&str> {
+        self.overview_md.as_deref()
+    }
+
+    pub(crate) fn status(&self) -> &str {
+        &self.status
+    }
+
+    pub(crate) fn task_id(&self) -> Option<&str> {
+        self.task_id.as_deref()
+    }
+
+    pub(crate) fn summary_md(&self) -> Option<&str> {
+        self.summary_md.as_deref()
+    }
+
+    pub(crate) fn keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// Renumbers speakers 0..N in order of first appearance and records the
+    /// original-to-new mapping in `speaker_map`.
+    pub(crate) fn normalize_speakers(&mut self) {
+        let mut map = HashMap::new();
+        for detail in &mut self.details {
+            let next = map.len() as i32;
+            detail.speaker = *map.entry(detail.speaker).or_insert(next);
         }
+        self.speaker_map = Some(map);
     }
 }
 
@@ -280,6 +479,24 @@ impl From<SummaryCreator> for SummaryCreateResponse {
     }
 }
 
+#[pyclass(module = "dianyaapi")]
+#[derive(Clone, Debug)]
+pub struct ChunkedSummaryResponse {
+    #[pyo3(get)]
+    chunks: Vec<SummaryCreateResponse>,
+    #[pyo3(get)]
+    combined: Option<SummaryCreateResponse>,
+}
+
+impl ChunkedSummaryResponse {
+    pub(crate) fn new(
+        chunks: Vec<SummaryCreateResponse>,
+        combined: Option<SummaryCreateResponse>,
+    ) -> Self {
+        Self { chunks, combined }
+    }
+}
+
 #[pyclass(module = "dianyaapi")]
 #[derive(Clone, Debug)]
 pub struct TextTranslationResponse {
@@ -298,6 +515,14 @@ impl From<TextTranslator> for TextTranslationResponse {
     }
 }
 
+impl TextTranslationResponse {
+    pub(crate) fn apply_glossary(&mut self, glossary: &Glossary) {
+        if !glossary.is_empty() {
+            self.data = glossary.apply(&self.data);
+        }
+    }
+}
+
 #[pyclass(module = "dianyaapi")]
 #[derive(Clone, Debug)]
 pub struct UtteranceTranslationResponse {
@@ -305,7 +530,7 @@ pub struct UtteranceTranslationResponse {
     status: String,
     #[pyo3(get)]
     target_language: String,
-    #[pyo3(get)]
+    #[pyo3(get, set)]
     details: Vec<UtterancePayload>,
 }
 
@@ -319,18 +544,33 @@ impl From<UtteranceTranslator> for UtteranceTranslationResponse {
     }
 }
 
+impl UtteranceTranslationResponse {
+    pub(crate) fn details(&self) -> &[UtterancePayload] {
+        &self.details
+    }
+
+    pub(crate) fn apply_glossary(&mut self, glossary: &Glossary) {
+        if glossary.is_empty() {
+            return;
+        }
+        for detail in &mut self.details {
+            detail.text = glossary.apply(&detail.text);
+        }
+    }
+}
+
 #[pyclass(module = "dianyaapi")]
 #[derive(Clone, Debug)]
 pub struct TranslationDetail {
-    #[pyo3(get)]
+    #[pyo3(get, set)]
     start_time: f64,
-    #[pyo3(get)]
+    #[pyo3(get, set)]
     end_time: f64,
-    #[pyo3(get)]
+    #[pyo3(get, set)]
     text: String,
-    #[pyo3(get)]
+    #[pyo3(get, set)]
     speaker: i32,
-    #[pyo3(get)]
+    #[pyo3(get, set)]
     translations: HashMap<String, String>,
 }
 
@@ -386,3 +626,706 @@ impl From<TranscribeTranslator> for TranscribeTranslationResponse {
         }
     }
 }
+
+/// Details of `current` that are new or whose text or translations differ
+/// from the detail with the same timing and speaker in `previous`.
+#[pyfunction]
+pub fn changed_translations(
+    previous: PyRef<'_, TranscribeTranslationResponse>,
+    current: PyRef<'_, TranscribeTranslationResponse>,
+) -> Vec<TranslationDetail> {
+    let key = |detail: &TranslationDetail| {
+        (
+            detail.start_time.to_bits(),
+            detail.end_time.to_bits(),
+            detail.speaker,
+        )
+    };
+    let before: HashMap<_, &TranslationDetail> = previous
+        .details
+        .iter()
+        .flatten()
+        .map(|detail| (key(detail), detail))
+        .collect();
+
+    current
+        .details
+        .iter()
+        .flatten()
+        .filter(|detail| {
+            before.get(&key(detail)).is_none_or(|old| {
+                old.text != detail.text || old.translations != detail.translations
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+impl TranscribeTranslationResponse {
+    /// Translated utterances with their original timing. Falls back to the
+    /// translation with the first language code, then to the source text.
+    pub(crate) fn translated_utterances(&self) -> Vec<Utterance> {
+        self.details
+            .iter()
+            .flatten()
+            .map(|detail| Utterance {
+                start_time: detail.start_time,
+                end_time: detail.end_time,
+                text: detail
+                    .translations
+                    .get(&self.target_language)
+                    .or_else(|| {
+                        let first = detail.translations.keys().min()?;
+                        detail.translations.get(first)
+                    })
+                    .unwrap_or(&detail.text)
+                    .clone(),
+                speaker: detail.speaker,
+            })
+            .collect()
+    }
+}
+
+#[pyclass(module = "dianyaapi")]
+#[derive(Clone, Debug)]
+pub struct TranslatedSummaryResponse {
+    #[pyo3(get)]
+    translation: TranscribeTranslationResponse,
+    #[pyo3(get)]
+    summary: StatusResponse,
+}
+
+impl TranslatedSummaryResponse {
+    pub(crate) fn new(translation: TranscribeTranslationResponse, summary: StatusResponse) -> Self {
+        Self {
+            translation,
+            summary,
+        }
+    }
+}
+
+// Python-facing representations. In reprs, collections are summarized by
+// length and long text is shortened so a repr stays one readable line;
+// `to_dict()` mirrors every field.
+
+impl SummaryContent {
+    fn eq_key(&self) -> (&str, &str, &str, &[String]) {
+        (&self.short, &self.long, &self.all, &self.keywords)
+    }
+}
+
+impl CallbackHistoryItem {
+    fn eq_key(&self) -> (&str, &str, u32) {
+        (&self.timestamp, &self.status, self.code)
+    }
+}
+
+/// `str()` form of one utterance, shared by the classes that hold them.
+fn transcript_line(start_time: f64, end_time: f64, speaker: i32, text: &str) -> String {
+    format!("[{start_time:.2}s-{end_time:.2}s] speaker {speaker}: {text}")
+}
+
+fn hash_key(key: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+macro_rules! impl_repr {
+    ($name:ident { $($field:ident),* $(,)? }) => {
+        impl ReprValue for $name {
+            fn repr_value(&self) -> String {
+                Repr::new(stringify!($name))
+                    $(.field(stringify!($field), &self.$field))*
+                    .finish()
+            }
+        }
+    };
+}
+
+macro_rules! impl_dict_value {
+    ($name:ident { $($field:ident),* $(,)? }) => {
+        impl DictValue for $name {
+            fn dict_value<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+                let dict = PyDict::new(py);
+                $(dict.set_item(stringify!($field), self.$field.dict_value(py)?)?;)*
+                Ok(dict.into_any())
+            }
+        }
+    };
+}
+
+/// Rebuilds a class from `to_dict()` output, or from JSON that held it. Any
+/// mapping works in place of a dict, and a nested value may also already be
+/// an instance of its class. Keys that are
+/// not fields are ignored; missing keys are only allowed for optional fields.
+macro_rules! impl_from_dict {
+    ($name:ident { $($field:ident),* $(,)? }) => {
+        impl FromDictValue for $name {
+            fn from_dict_value(value: &Bound<'_, PyAny>, path: &str) -> PyResult<Self> {
+                if let Ok(object) = value.cast::<Self>() {
+                    return Ok(object.borrow().clone());
+                }
+                let Ok(mapping) = value.cast::<PyMapping>() else {
+                    return Err(mismatched(value, path, "a dict"));
+                };
+                Ok(Self {
+                    $($field: dict_field(mapping, path, stringify!($field))?,)*
+                })
+            }
+        }
+
+        impl $name {
+            fn parse_dict(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+                Self::from_dict_value(data, stringify!($name))
+            }
+        }
+    };
+}
+
+/// Response classes have no Python constructor, so `__reduce__` rebuilds them
+/// through the `_from_state` static method rather than `__new__` plus
+/// `__setstate__`. The state is the tuple of the fields listed here.
+macro_rules! impl_pickle {
+    ($name:ident { $($field:ident),* $(,)? }) => {
+        impl StateValue for $name {
+            fn state_value<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+                Ok(Bound::new(py, self.clone())?.into_any())
+            }
+
+            fn from_state(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+                Ok(value.cast::<Self>()?.borrow().clone())
+            }
+        }
+
+        impl $name {
+            fn pickle_state<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+                PyTuple::new(py, [$(self.$field.state_value(py)?),*])
+            }
+
+            fn from_pickle_state(state: &Bound<'_, PyTuple>) -> PyResult<Self> {
+                let mut items = state.iter();
+                let mut next = || items.next().ok_or_else(|| invalid_state(stringify!($name)));
+                let value = Self {
+                    $($field: StateValue::from_state(&next()?)?,)*
+                };
+                if items.next().is_some() {
+                    return Err(invalid_state(stringify!($name)));
+                }
+                Ok(value)
+            }
+        }
+    };
+}
+
+/// Implements the repr, `to_dict()` and pickling of a class from one list of
+/// fields, plus `from_dict()` for classes marked `from_dict`. The list sets
+/// the order of the repr, the dict keys and the pickled state alike.
+macro_rules! impl_fields {
+    ($name:ident { $($field:ident),* $(,)? }, from_dict) => {
+        impl_fields!($name { $($field),* });
+        impl_from_dict!($name { $($field),* });
+    };
+    ($name:ident { $($field:ident),* $(,)? }) => {
+        impl_repr!($name { $($field),* });
+        impl_dict_value!($name { $($field),* });
+        impl_pickle!($name { $($field),* });
+    };
+}
+
+/// Emits the class's single `#[pymethods]` block: the methods every response
+/// class shares, followed by the class's own.
+macro_rules! impl_pymethods {
+    ($name:ident) => {
+        impl_pymethods!($name {});
+    };
+    ($name:ident { $($methods:tt)* }) => {
+        #[pymethods]
+        impl $name {
+            pub fn __repr__(&self) -> String {
+                self.repr_value()
+            }
+
+            pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+                self.dict_value(py)
+            }
+
+            pub fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+                self.pickle_state(py)
+            }
+
+            pub fn __reduce__<'py>(
+                &self,
+                py: Python<'py>,
+            ) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, PyTuple>,))> {
+                Ok((py.get_type::<Self>().getattr("_from_state")?, (self.pickle_state(py)?,)))
+            }
+
+            #[staticmethod]
+            #[pyo3(name = "_from_state")]
+            pub fn unpickle(state: &Bound<'_, PyTuple>) -> PyResult<Self> {
+                Self::from_pickle_state(state)
+            }
+
+            $($methods)*
+        }
+    };
+}
+
+impl_fields!(SessionCreateResult {
+    task_id,
+    session_id,
+    usage_id,
+    max_time
+});
+impl_fields!(SessionCloseResult {
+    status,
+    duration,
+    error_code,
+    message
+});
+impl_fields!(StreamStats {
+    frames_sent,
+    bytes_sent,
+    messages_received,
+    bytes_received,
+    reconnects,
+    elapsed_seconds
+});
+impl_fields!(StreamMessage {
+    message_type,
+    is_final,
+    utterances,
+    raw
+});
+impl_fields!(ModelRecommendation {
+    model,
+    short_asr,
+    transcribe_only,
+    duration
+});
+impl_fields!(CoverageReport {
+    covered_seconds,
+    audio_seconds,
+    fraction,
+    likely_truncated
+});
+impl_fields!(UploadResult {
+    kind,
+    task_id,
+    status,
+    message,
+    data,
+    model
+});
+impl_fields!(UploadOutcome {
+    filepath,
+    result,
+    error
+});
+impl_fields!(
+    UtterancePayload {
+        start_time,
+        end_time,
+        text,
+        speaker
+    },
+    from_dict
+);
+impl_fields!(SummaryContent {
+    short,
+    long,
+    all,
+    keywords
+});
+impl_fields!(
+    CallbackHistoryItem {
+        timestamp,
+        status,
+        code
+    },
+    from_dict
+);
+impl_fields!(
+    StatusResponse {
+        status,
+        overview_md,
+        summary_md,
+        details,
+        message,
+        usage_id,
+        task_id,
+        keywords,
+        callback_history,
+        task_type,
+        speaker_map
+    },
+    from_dict
+);
+impl_fields!(CallbackResponse { status });
+impl_fields!(ShareLinkResponse {
+    share_url,
+    expiration_time,
+    expired_at
+});
+impl_fields!(SummaryCreateResponse { task_id });
+impl_fields!(ChunkedSummaryResponse { chunks, combined });
+impl_fields!(TextTranslationResponse { status, data }, from_dict);
+impl_fields!(
+    UtteranceTranslationResponse {
+        status,
+        target_language,
+        details
+    },
+    from_dict
+);
+impl_fields!(
+    TranslationDetail {
+        start_time,
+        end_time,
+        text,
+        speaker,
+        translations
+    },
+    from_dict
+);
+impl_fields!(
+    TranscribeTranslationResponse {
+        task_id,
+        task_type,
+        status,
+        target_language,
+        message,
+        details,
+        overview_md,
+        summary_md,
+        keywords
+    },
+    from_dict
+);
+impl_fields!(
+    TranslatedSummaryResponse {
+        translation,
+        summary
+    },
+    from_dict
+);
+
+impl_pymethods!(SessionCreateResult);
+
+impl_pymethods!(SessionCloseResult);
+
+impl_pymethods!(StreamStats);
+
+impl_pymethods!(StreamMessage {
+    /// First utterance of the frame, which is all most frames carry.
+    #[getter]
+    pub fn utterance(&self) -> Option<UtterancePayload> {
+        self.utterances.first().cloned()
+    }
+});
+
+impl_pymethods!(ModelRecommendation);
+
+impl_pymethods!(CoverageReport);
+
+impl_pymethods!(UploadResult);
+
+impl_pymethods!(UploadOutcome {
+    #[getter]
+    pub fn ok(&self) -> bool {
+        self.error.is_none()
+    }
+});
+
+impl_pymethods!(UtterancePayload {
+    #[staticmethod]
+    pub fn from_dict(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Self::parse_dict(data)
+    }
+
+    /// Times compare as floats, so `0.0 == -0.0` and NaN equals nothing. The
+    /// fields are writable, so there is no `__hash__` and Python makes
+    /// instances unhashable.
+    pub fn __eq__(&self, other: &Self) -> bool {
+        self.start_time == other.start_time
+            && self.end_time == other.end_time
+            && self.text == other.text
+            && self.speaker == other.speaker
+    }
+
+    pub fn __str__(&self) -> String {
+        transcript_line(self.start_time, self.end_time, self.speaker, &self.text)
+    }
+});
+
+impl_pymethods!(SummaryContent {
+    pub fn __eq__(&self, other: &Self) -> bool {
+        self.eq_key() == other.eq_key()
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        hash_key(&self.eq_key())
+    }
+});
+
+impl_pymethods!(CallbackHistoryItem {
+    #[staticmethod]
+    pub fn from_dict(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Self::parse_dict(data)
+    }
+
+    pub fn __eq__(&self, other: &Self) -> bool {
+        self.eq_key() == other.eq_key()
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        hash_key(&self.eq_key())
+    }
+});
+
+impl_pymethods!(StatusResponse {
+    #[staticmethod]
+    pub fn from_dict(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Self::parse_dict(data)
+    }
+
+    /// The `details` getter hands out copies, so edits to them only stick
+    /// once the list is stored back; this does so on a new response.
+    pub fn with_details(&self, details: Vec<UtterancePayload>) -> Self {
+        Self {
+            details,
+            ..self.clone()
+        }
+    }
+
+    #[pyo3(signature = (keyword, *, case_sensitive=false))]
+    pub fn has_keyword(&self, keyword: &str, case_sensitive: bool) -> bool {
+        if case_sensitive {
+            return self.keywords.iter().any(|candidate| candidate == keyword);
+        }
+        let keyword = keyword.to_lowercase();
+        self.keywords
+            .iter()
+            .any(|candidate| candidate.to_lowercase() == keyword)
+    }
+
+    #[pyo3(signature = (query, *, case_sensitive=false))]
+    pub fn find_utterances(&self, query: &str, case_sensitive: bool) -> Vec<UtterancePayload> {
+        if case_sensitive {
+            return self
+                .details
+                .iter()
+                .filter(|detail| detail.text.contains(query))
+                .cloned()
+                .collect();
+        }
+        let query = query.to_lowercase();
+        self.details
+            .iter()
+            .filter(|detail| detail.text.to_lowercase().contains(&query))
+            .cloned()
+            .collect()
+    }
+
+    /// Seconds from the first utterance's start to the last one's end, gaps
+    /// included.
+    pub fn total_duration(&self) -> f64 {
+        let start = self.details.iter().map(|d| d.start_time).reduce(f64::min);
+        let end = self.details.iter().map(|d| d.end_time).reduce(f64::max);
+        match (start, end) {
+            (Some(start), Some(end)) => end - start,
+            _ => 0.0,
+        }
+    }
+
+    /// Sum of the utterances' own durations, so silence between them is not
+    /// counted. Overlapping speakers are each counted in full.
+    pub fn speaking_time(&self) -> f64 {
+        self.details
+            .iter()
+            .map(|detail| (detail.end_time - detail.start_time).max(0.0))
+            .sum()
+    }
+
+    pub fn utterances_by_speaker(&self, speaker: i32) -> Vec<UtterancePayload> {
+        self.details
+            .iter()
+            .filter(|detail| detail.speaker == speaker)
+            .cloned()
+            .collect()
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.details.len()
+    }
+
+    pub fn __getitem__(&self, index: isize) -> PyResult<UtterancePayload> {
+        let position = if index < 0 {
+            index.checked_add_unsigned(self.details.len())
+        } else {
+            Some(index)
+        };
+        position
+            .and_then(|position| usize::try_from(position).ok())
+            .and_then(|position| self.details.get(position))
+            .cloned()
+            .ok_or_else(|| PyIndexError::new_err("utterance index out of range"))
+    }
+
+    pub fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        PyList::new(py, self.details.clone())?.try_iter()
+    }
+
+    /// Membership follows iteration, so only utterances can be contained;
+    /// keywords are looked up with `has_keyword`.
+    pub fn __contains__(&self, item: &Bound<'_, PyAny>) -> bool {
+        item.cast::<UtterancePayload>().is_ok_and(|utterance| {
+            let utterance = utterance.borrow();
+            self.details.iter().any(|detail| detail.__eq__(&utterance))
+        })
+    }
+
+    /// A response is truthy even without utterances, as `__len__` would
+    /// otherwise make a finished empty task falsy.
+    pub fn __bool__(&self) -> bool {
+        true
+    }
+
+    /// The transcript, one `UtterancePayload` line per utterance; the repr
+    /// when there are none.
+    pub fn __str__(&self) -> String {
+        if self.details.is_empty() {
+            return self.repr_value();
+        }
+        let lines: Vec<String> = self.details.iter().map(UtterancePayload::__str__).collect();
+        lines.join("\n")
+    }
+});
+
+impl_pymethods!(CallbackResponse);
+
+impl_pymethods!(ShareLinkResponse);
+
+impl_pymethods!(SummaryCreateResponse);
+
+impl_pymethods!(ChunkedSummaryResponse);
+
+impl_pymethods!(TextTranslationResponse {
+    #[staticmethod]
+    pub fn from_dict(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Self::parse_dict(data)
+    }
+});
+
+impl_pymethods!(UtteranceTranslationResponse {
+    #[staticmethod]
+    pub fn from_dict(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Self::parse_dict(data)
+    }
+});
+
+impl_pymethods!(TranslationDetail {
+    #[staticmethod]
+    pub fn from_dict(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Self::parse_dict(data)
+    }
+});
+
+impl_pymethods!(TranscribeTranslationResponse {
+    #[staticmethod]
+    pub fn from_dict(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Self::parse_dict(data)
+    }
+
+    /// The translated transcript, one line per utterance in the target
+    /// language (the original text where it has no translation); the repr
+    /// when there are no details.
+    pub fn __str__(&self) -> String {
+        let Some(details) = self.details.as_deref().filter(|details| !details.is_empty()) else {
+            return self.repr_value();
+        };
+        let lines: Vec<String> = details
+            .iter()
+            .map(|detail| {
+                let text = detail
+                    .translations
+                    .get(&self.target_language)
+                    .unwrap_or(&detail.text);
+                transcript_line(detail.start_time, detail.end_time, detail.speaker, text)
+            })
+            .collect();
+        lines.join("\n")
+    }
+});
+
+impl_pymethods!(TranslatedSummaryResponse {
+    #[staticmethod]
+    pub fn from_dict(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Self::parse_dict(data)
+    }
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stream_message_keeps_every_utterance_of_a_frame() {
+        let raw = r#"{"type": "asr_final", "data": [
+            {"start_time": 0, "end_time": 1, "text": "你好", "speaker": 0},
+            {"start_time": 1, "end_time": 2, "text": "hello", "speaker": 1}
+        ]}"#;
+        let message = StreamMessage::from(raw.to_string());
+        assert_eq!(message.message_type, "asr_final");
+        assert!(message.is_final);
+        let speakers: Vec<_> = message.utterances.iter().map(|u| u.speaker).collect();
+        assert_eq!(speakers, [0, 1]);
+        assert_eq!(message.raw, raw);
+    }
+
+    #[test]
+    fn malformed_frames_become_unknown_messages() {
+        for raw in ["not json", "[]", r#"{"text": "no type"}"#] {
+            let message = StreamMessage::from(raw.to_string());
+            assert_eq!(message.message_type, "unknown", "{raw}");
+            assert!(!message.is_final, "{raw}");
+            assert_eq!(message.raw, raw);
+        }
+    }
+
+    #[test]
+    fn translated_utterances_fall_back_to_the_first_language() {
+        let detail = |translations: &[(&str, &str)]| TranslationDetail {
+            start_time: 0.0,
+            end_time: 1.0,
+            text: "你好".to_string(),
+            speaker: 0,
+            translations: translations
+                .iter()
+                .map(|(language, text)| (language.to_string(), text.to_string()))
+                .collect(),
+        };
+        let response = TranscribeTranslationResponse {
+            task_id: "task-1".to_string(),
+            task_type: "transcribe".to_string(),
+            status: "done".to_string(),
+            target_language: "en".to_string(),
+            message: None,
+            details: Some(vec![
+                detail(&[("ja", "こんにちは"), ("en", "Hello")]),
+                detail(&[("ko", "안녕하세요"), ("de", "Hallo"), ("ja", "こんにちは")]),
+                detail(&[]),
+            ]),
+            overview_md: None,
+            summary_md: None,
+            keywords: None,
+        };
+        let texts: Vec<_> = response
+            .translated_utterances()
+            .into_iter()
+            .map(|utterance| utterance.text)
+            .collect();
+        assert_eq!(texts, ["Hello", "Hallo", "你好"]);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::errors::invalid_input;
+use pyo3::prelude::*;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Projects quota exhaustion from successive usage snapshots. The SDK has no
+/// usage endpoint, so callers feed in whatever remaining amount they track.
+#[pyclass(module = "dianyaapi")]
+#[derive(Clone, Debug, Default)]
+pub struct QuotaTracker {
+    /// `(unix_seconds, remaining)`, oldest first.
+    snapshots: Vec<(f64, f64)>,
+}
+
+#[pymethods]
+impl QuotaTracker {
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[pyo3(signature = (remaining, timestamp=None))]
+    pub fn record(&mut self, remaining: f64, timestamp: Option<f64>) -> PyResult<()> {
+        let timestamp = match timestamp {
+            Some(timestamp) => timestamp,
+            None => SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs_f64())
+                .unwrap_or_default(),
+        };
+        if !remaining.is_finite() || !timestamp.is_finite() {
+            return Err(invalid_input("remaining and timestamp must be finite"));
+        }
+        if let Some(&(last, _)) = self.snapshots.last()
+            && timestamp <= last
+        {
+            return Err(invalid_input(format!(
+                "snapshot at {timestamp} is not after the previous one at {last}"
+            )));
+        }
+        self.snapshots.push((timestamp, remaining));
+        Ok(())
+    }
+
+    /// Average consumption per second between the first and last snapshots.
+    pub fn rate(&self) -> Option<f64> {
+        let (&(start, first), &(end, last)) = (self.snapshots.first()?, self.snapshots.last()?);
+        (end > start).then(|| (first - last) / (end - start))
+    }
+
+    /// Seconds from the last snapshot until the quota runs out, or None when
+    /// nothing is being consumed.
+    pub fn eta_seconds(&self) -> Option<f64> {
+        let rate = self.rate().filter(|rate| *rate > 0.0)?;
+        let &(_, remaining) = self.snapshots.last()?;
+        Some((remaining / rate).max(0.0))
+    }
+
+    /// Projected unix time of exhaustion.
+    pub fn exhaustion_time(&self) -> Option<f64> {
+        let &(last, _) = self.snapshots.last()?;
+        Some(last + self.eta_seconds()?)
+    }
+
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.snapshots.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_snapshots_project_exhaustion() {
+        let mut tracker = QuotaTracker::new();
+        tracker.record(100.0, Some(1_000.0)).unwrap();
+        assert_eq!(tracker.rate(), None);
+        tracker.record(80.0, Some(1_010.0)).unwrap();
+        assert_eq!(tracker.rate(), Some(2.0));
+        assert_eq!(tracker.eta_seconds(), Some(40.0));
+        assert_eq!(tracker.exhaustion_time(), Some(1_050.0));
+    }
+
+    #[test]
+    fn no_projection_without_consumption() {
+        let mut tracker = QuotaTracker::new();
+        tracker.record(50.0, Some(0.0)).unwrap();
+        tracker.record(60.0, Some(30.0)).unwrap();
+        assert_eq!(tracker.eta_seconds(), None);
+        assert_eq!(tracker.exhaustion_time(), None);
+    }
+
+    #[test]
+    fn snapshots_must_move_forward() {
+        Python::initialize();
+        let mut tracker = QuotaTracker::new();
+        tracker.record(10.0, Some(5.0)).unwrap();
+        assert!(tracker.record(9.0, Some(5.0)).is_err());
+        assert!(tracker.record(f64::NAN, Some(6.0)).is_err());
+        assert_eq!(tracker.__len__(), 1);
+    }
+}
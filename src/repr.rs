@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Longest text shown verbatim in a repr before it is cut with `...`.
+const PREVIEW_CHARS: usize = 40;
+
+/// Python-literal rendering of a field value, with long collections summarized.
+pub trait ReprValue {
+    fn repr_value(&self) -> String;
+}
+
+/// Quotes like Python's `repr(str)`.
+fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('\'');
+    for ch in text.chars() {
+        match ch {
+            '\\' => quoted.push_str("\\\\"),
+            '\'' => quoted.push_str("\\'"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            other => quoted.push(other),
+        }
+    }
+    quoted.push('\'');
+    quoted
+}
+
+pub fn preview(text: &str) -> String {
+    if text.chars().count() <= PREVIEW_CHARS {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(PREVIEW_CHARS).collect();
+    format!("{cut}...")
+}
+
+impl ReprValue for String {
+    fn repr_value(&self) -> String {
+        quote(&preview(self))
+    }
+}
+
+impl ReprValue for bool {
+    fn repr_value(&self) -> String {
+        if *self { "True" } else { "False" }.to_string()
+    }
+}
+
+macro_rules! display_repr {
+    ($($ty:ty),*) => {
+        $(impl ReprValue for $ty {
+            fn repr_value(&self) -> String {
+                self.to_string()
+            }
+        })*
+    };
+}
+
+display_repr!(i32, u32, u64, usize);
+
+impl ReprValue for f64 {
+    fn repr_value(&self) -> String {
+        // Match Python, which always shows a float with a fractional part.
+        if self.is_finite() && self.fract() == 0.0 {
+            format!("{self:.1}")
+        } else {
+            self.to_string()
+        }
+    }
+}
+
+impl<T: ReprValue> ReprValue for Option<T> {
+    fn repr_value(&self) -> String {
+        self.as_ref()
+            .map_or_else(|| "None".to_string(), ReprValue::repr_value)
+    }
+}
+
+impl<T> ReprValue for Vec<T> {
+    fn repr_value(&self) -> String {
+        match self.len() {
+            1 => "[1 item]".to_string(),
+            len => format!("[{len} items]"),
+        }
+    }
+}
+
+impl<K, V> ReprValue for HashMap<K, V> {
+    fn repr_value(&self) -> String {
+        match self.len() {
+            1 => "{1 entry}".to_string(),
+            len => format!("{{{len} entries}}"),
+        }
+    }
+}
+
+/// Builds `Name(field=value, ...)`.
+pub struct Repr {
+    out: String,
+    empty: bool,
+}
+
+impl Repr {
+    pub fn new(name: &str) -> Self {
+        Self {
+            out: format!("{name}("),
+            empty: true,
+        }
+    }
+
+    pub fn field(self, name: &str, value: &impl ReprValue) -> Self {
+        self.raw(name, &value.repr_value())
+    }
+
+    /// Adds a field whose value is already rendered, e.g. a nested repr.
+    pub fn raw(mut self, name: &str, rendered: &str) -> Self {
+        if !self.empty {
+            self.out.push_str(", ");
+        }
+        self.empty = false;
+        let _ = write!(self.out, "{name}={rendered}");
+        self
+    }
+
+    pub fn finish(mut self) -> String {
+        self.out.push(')');
+        self.out
+    }
+}
@@ -0,0 +1,263 @@
+use crate::{
+    errors::{http_status, invalid_input},
+    repr::Repr,
+    types::parse_seconds,
+};
+use common::Error;
+use pyo3::prelude::*;
+use std::{
+    fmt::Display,
+    future::Future,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Exponential backoff for transient failures of a single request.
+#[pyclass(module = "dianyaapi")]
+#[derive(Clone, Debug)]
+pub struct RetryConfig {
+    #[pyo3(get)]
+    max_attempts: u32,
+    initial_delay: Duration,
+    #[pyo3(get)]
+    multiplier: f64,
+    max_delay: Duration,
+}
+
+#[pymethods]
+impl RetryConfig {
+    #[new]
+    #[pyo3(signature = (
+        *, max_attempts=3, initial_delay_seconds=0.5, multiplier=2.0, max_delay_seconds=10.0
+    ))]
+    pub fn new(
+        max_attempts: u32,
+        initial_delay_seconds: f64,
+        multiplier: f64,
+        max_delay_seconds: f64,
+    ) -> PyResult<Self> {
+        if max_attempts == 0 {
+            return Err(invalid_input("max_attempts must be at least 1"));
+        }
+        if !multiplier.is_finite() || multiplier < 1.0 {
+            return Err(invalid_input("multiplier must be at least 1"));
+        }
+        Ok(Self {
+            max_attempts,
+            initial_delay: parse_seconds(initial_delay_seconds, "initial_delay_seconds")?,
+            multiplier,
+            max_delay: parse_seconds(max_delay_seconds, "max_delay_seconds")?,
+        })
+    }
+
+    #[getter]
+    pub fn initial_delay_seconds(&self) -> f64 {
+        self.initial_delay.as_secs_f64()
+    }
+
+    #[getter]
+    pub fn max_delay_seconds(&self) -> f64 {
+        self.max_delay.as_secs_f64()
+    }
+
+    pub fn __repr__(&self) -> String {
+        Repr::new("RetryConfig")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_delay_seconds", &self.initial_delay_seconds())
+            .field("multiplier", &self.multiplier)
+            .field("max_delay_seconds", &self.max_delay_seconds())
+            .finish()
+    }
+}
+
+impl RetryConfig {
+    /// Delay before retry number `retry` (starting at 1), capped at
+    /// `max_delay` and scaled by a jitter factor in [0.5, 1).
+    fn delay(&self, retry: u32) -> Duration {
+        let exponent = i32::try_from(retry - 1).unwrap_or(i32::MAX);
+        let base = (self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent))
+            .min(self.max_delay.as_secs_f64());
+        // Sub-second clock noise is enough to keep clients from retrying in
+        // lockstep; it does not need to be a real random source.
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.subsec_nanos())
+            .unwrap_or_default();
+        let jitter = 0.5 + f64::from(nanos % 1000) / 2000.0;
+        Duration::from_secs_f64(base * jitter)
+    }
+}
+
+/// Timeouts, dropped connections, rate limiting and 5xx responses. The SDK
+/// reports all of them as plain errors, so they are recognised by message;
+/// status codes only as `http_status` reads them.
+pub fn is_retryable(message: &str) -> bool {
+    let message = message.to_ascii_lowercase();
+    let transient = [
+        "timed out",
+        "timeout",
+        "connection reset",
+        "connection refused",
+        "connection closed",
+        "temporarily unavailable",
+        "too many requests",
+    ];
+    transient.iter().any(|reason| message.contains(reason))
+        || http_status(&message).is_some_and(|status| status == 429 || status >= 500)
+}
+
+/// Runs `call` until it succeeds, fails with a non-retryable error or runs
+/// out of attempts. Without a config the call is made exactly once.
+pub async fn with_retry<T, F, Fut>(config: Option<&RetryConfig>, call: F) -> Result<T, Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    retry_while(config, call, |err| {
+        !matches!(err, Error::InvalidInput(_)) && is_retryable(&err.to_string())
+    })
+    .await
+}
+
+/// `with_retry` for any error type, retrying the errors `retryable` accepts.
+async fn retry_while<T, E, F, Fut>(
+    config: Option<&RetryConfig>,
+    mut call: F,
+    retryable: impl Fn(&E) -> bool,
+) -> Result<T, E>
+where
+    E: Display,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let Some(config) = config else {
+        return call().await;
+    };
+    let mut attempt = 1;
+    loop {
+        match call().await {
+            Err(err) if attempt < config.max_attempts && retryable(&err) => {
+                let delay = config.delay(attempt);
+                log::info!(
+                    "attempt {attempt} of {} failed, retrying in {:.2}s: {err}",
+                    config.max_attempts,
+                    delay.as_secs_f64()
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Parses an already validated argument again inside a retried call, so SDK
+/// values that cannot be cloned are rebuilt for every attempt.
+pub fn reparse<T>(parsed: PyResult<T>) -> Result<T, Error> {
+    parsed.map_err(|err| Error::InvalidInput(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn retries_transient_failures() {
+        assert!(is_retryable("operation timed out"));
+        assert!(is_retryable("Connection reset by peer"));
+        assert!(is_retryable("HTTP 503"));
+        assert!(is_retryable("status: 500"));
+        assert!(is_retryable("429 Too Many Requests"));
+        assert!(is_retryable("502 Bad Gateway"));
+    }
+
+    #[test]
+    fn does_not_retry_other_failures() {
+        assert!(!is_retryable(
+            "uploaded 512 bytes before the upload was rejected"
+        ));
+        assert!(!is_retryable("processed 429 items"));
+        assert!(!is_retryable("HTTP 404"));
+        assert!(!is_retryable("HTTP 401 unauthorized"));
+        assert!(!is_retryable("invalid file format"));
+    }
+
+    fn config(max_attempts: u32) -> RetryConfig {
+        RetryConfig::new(max_attempts, 1.0, 2.0, 5.0).unwrap()
+    }
+
+    /// Makes the calls `retry_while` sees, failing with `failures` in order
+    /// and succeeding once they run out.
+    async fn run(config: Option<&RetryConfig>, failures: &[&str]) -> (Result<(), String>, usize) {
+        let calls = Cell::new(0);
+        let result = retry_while(
+            config,
+            || {
+                let call = calls.get();
+                calls.set(call + 1);
+                let outcome = failures
+                    .get(call)
+                    .map_or(Ok(()), |err| Err(err.to_string()));
+                async move { outcome }
+            },
+            |err: &String| err != "invalid input" && is_retryable(err),
+        )
+        .await;
+        (result, calls.get())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn transient_failures_are_retried_until_success() {
+        let (result, calls) = run(Some(&config(3)), &["timed out", "HTTP 503"]).await;
+        assert_eq!(result, Ok(()));
+        assert_eq!(calls, 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn invalid_input_is_not_retried() {
+        let (result, calls) = run(Some(&config(3)), &["invalid input"]).await;
+        assert_eq!(result, Err("invalid input".to_string()));
+        assert_eq!(calls, 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn non_retryable_failures_are_not_retried() {
+        let (result, calls) = run(Some(&config(3)), &["HTTP 401 unauthorized"]).await;
+        assert_eq!(result, Err("HTTP 401 unauthorized".to_string()));
+        assert_eq!(calls, 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn exhausted_attempts_return_the_last_error() {
+        let (result, calls) = run(Some(&config(2)), &["timed out", "HTTP 502", "timed out"]).await;
+        assert_eq!(result, Err("HTTP 502".to_string()));
+        assert_eq!(calls, 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn without_a_config_the_call_is_made_once() {
+        let (result, calls) = run(None, &["timed out"]).await;
+        assert_eq!(result, Err("timed out".to_string()));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn delay_grows_with_jitter_and_stays_capped() {
+        let config = config(10);
+        for (retry, base) in [
+            (1, 1.0),
+            (2, 2.0),
+            (3, 4.0),
+            (4, 5.0),
+            (9, 5.0),
+            (u32::MAX, 5.0),
+        ] {
+            let delay = config.delay(retry).as_secs_f64();
+            assert!(
+                (0.5 * base..base).contains(&delay),
+                "retry {retry}: {delay}"
+            );
+            assert!(delay <= config.max_delay.as_secs_f64());
+        }
+    }
+}
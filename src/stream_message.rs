@@ -0,0 +1,159 @@
+use crate::types::from_value_lenient;
+use serde_json::{Map, Value};
+use transcribe::Utterance;
+
+/// Keys under which servers have been seen to put the message kind.
+const TYPE_KEYS: [&str; 3] = ["type", "event", "action"];
+/// Keys that may hold one utterance object or a list of them.
+const UTTERANCE_KEYS: [&str; 5] = ["data", "result", "utterance", "utterances", "details"];
+const FINAL_TYPES: [&str; 4] = ["final", "asr_final", "sentence_end", "complete"];
+
+/// Best-effort view of one streaming frame. The realtime protocol is not
+/// versioned, so every field is optional and unknown shapes degrade to an
+/// empty, non-final message instead of failing.
+#[derive(Debug, Default)]
+pub struct ParsedMessage {
+    pub message_type: Option<String>,
+    pub is_final: bool,
+    pub utterances: Vec<Utterance>,
+}
+
+fn message_type(object: &Map<String, Value>) -> Option<&str> {
+    TYPE_KEYS
+        .iter()
+        .find_map(|key| object.get(*key).and_then(Value::as_str))
+}
+
+fn final_flag(object: &Map<String, Value>) -> Option<bool> {
+    match object.get("is_final").or_else(|| object.get("final")) {
+        Some(Value::Bool(flag)) => Some(*flag),
+        _ => UTTERANCE_KEYS
+            .iter()
+            .filter_map(|key| object.get(*key).and_then(Value::as_object))
+            .find_map(final_flag),
+    }
+}
+
+fn collect_utterances(value: &Value, utterances: &mut Vec<Utterance>) {
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .for_each(|item| collect_utterances(item, utterances)),
+        Value::Object(object) if object.contains_key("text") => {
+            if let Ok(utterance) = from_value_lenient(value.clone()) {
+                utterances.push(utterance);
+            }
+        }
+        Value::Object(object) => UTTERANCE_KEYS
+            .iter()
+            .filter_map(|key| object.get(*key))
+            .for_each(|nested| collect_utterances(nested, utterances)),
+        _ => {}
+    }
+}
+
+/// Whether the frame is an event of the given kind. Finals are also
+/// recognized by their `is_final` flag, since not every server tags them.
+pub fn is_event(raw: &str, event_type: &str) -> bool {
+    let Ok(Value::Object(object)) = serde_json::from_str::<Value>(raw) else {
+        return false;
+    };
+    if message_type(&object).is_some_and(|kind| kind.eq_ignore_ascii_case(event_type)) {
+        return true;
+    }
+    event_type.eq_ignore_ascii_case("final") && parse_message(raw).is_final
+}
+
+pub fn parse_message(raw: &str) -> ParsedMessage {
+    let Ok(Value::Object(object)) = serde_json::from_str::<Value>(raw) else {
+        return ParsedMessage::default();
+    };
+
+    let kind = message_type(&object).map(str::to_string);
+    let is_final = final_flag(&object).unwrap_or_else(|| {
+        message_type(&object)
+            .is_some_and(|kind| FINAL_TYPES.contains(&kind.to_ascii_lowercase().as_str()))
+    });
+    let mut utterances = Vec::new();
+    collect_utterances(&Value::Object(object), &mut utterances);
+
+    ParsedMessage {
+        message_type: kind,
+        is_final,
+        utterances,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const U: &str = r#"{"start_time": 0, "end_time": 1.5, "text": "你好", "speaker": 1}"#;
+
+    #[test]
+    fn parse_message_classifies_frames() {
+        let cases = [
+            (
+                format!(r#"{{"type": "asr_final", "data": {U}}}"#),
+                Some("asr_final"),
+                true,
+                1,
+            ),
+            (
+                format!(r#"{{"event": "partial", "data": {U}}}"#),
+                Some("partial"),
+                false,
+                1,
+            ),
+            (
+                format!(r#"{{"type": "result", "is_final": true, "utterances": [{U}, {U}]}}"#),
+                Some("result"),
+                true,
+                2,
+            ),
+            (
+                format!(r#"{{"type": "final", "data": {{"is_final": false, "result": {U}}}}}"#),
+                Some("final"),
+                false,
+                1,
+            ),
+            (
+                r#"{"action": "Sentence_End"}"#.to_string(),
+                Some("Sentence_End"),
+                true,
+                0,
+            ),
+            (
+                r#"{"type": "heartbeat"}"#.to_string(),
+                Some("heartbeat"),
+                false,
+                0,
+            ),
+            (r#"{"data": {"text": 1}}"#.to_string(), None, false, 0),
+            ("[1, 2]".to_string(), None, false, 0),
+            ("not json".to_string(), None, false, 0),
+        ];
+        for (raw, kind, is_final, count) in cases {
+            let parsed = parse_message(&raw);
+            assert_eq!(parsed.message_type.as_deref(), kind, "{raw}");
+            assert_eq!(parsed.is_final, is_final, "{raw}");
+            assert_eq!(parsed.utterances.len(), count, "{raw}");
+        }
+    }
+
+    #[test]
+    fn is_event_matches_type_or_final_flag() {
+        let cases = [
+            (r#"{"type": "Final"}"#, "final", true),
+            (r#"{"is_final": true}"#, "final", true),
+            (r#"{"type": "asr_final"}"#, "final", true),
+            (r#"{"type": "partial"}"#, "final", false),
+            (r#"{"event": "error"}"#, "Error", true),
+            (r#"{"event": "error"}"#, "final", false),
+            ("not json", "error", false),
+        ];
+        for (raw, event_type, expected) in cases {
+            assert_eq!(is_event(raw, event_type), expected, "{raw} as {event_type}");
+        }
+    }
+}
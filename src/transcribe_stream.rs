@@ -1,100 +1,1009 @@
 use crate::{
-    py_types::{SessionCloseResult, SessionCreateResult},
-    types::parse_model,
+    audio::{STREAM_BYTE_RATE, stream_samples},
+    errors::{DianyaStreamError, StreamClosedError, invalid_input, to_py_err, to_py_err_in},
+    py_types::{
+        SessionCloseResult, SessionCreateResult, StreamMessage, StreamStats, UtterancePayload,
+    },
+    retry::{RetryConfig, reparse, with_retry},
+    stream_message::{is_event, parse_message},
+    transcribe_wrapper::within_timeout,
+    types::{TaskOutcome, extract_bytes, parse_interval, parse_model, parse_seconds, task_outcome},
+};
+use pyo3::{
+    exceptions::{PyStopAsyncIteration, PyTimeoutError},
+    prelude::*,
+};
+use serde_json::json;
+use std::{
+    fs::{File, OpenOptions},
+    future::Future,
+    io::Write,
+    pin::Pin,
+    sync::{
+        Arc, Mutex as StdMutex,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+use stream_cancel::{Trigger, Valved};
+use tokio::{
+    sync::{Mutex, mpsc, oneshot, watch},
+    task::JoinHandle,
+};
+use tokio_stream::{Stream, StreamExt, wrappers::ReceiverStream};
+use transcribe::{
+    Utterance,
+    transcribe::{
+        SessionCreator, SessionEnder, TranscribeWs, close_session, create_session,
+        status as transcribe_status,
+    },
 };
-use common::Error;
-use pyo3::prelude::*;
-use std::{pin::Pin, sync::Arc, time::Duration};
-use stream_cancel::Valved;
-use tokio::sync::Mutex;
-use tokio_stream::{Stream, StreamExt};
-use transcribe::transcribe::{TranscribeWs, close_session, create_session};
 use tungstenite::{Message, Utf8Bytes};
 
+type FrameStream = Pin<Box<dyn Stream<Item = Utf8Bytes> + Send>>;
+
+/// Session creation can race with the teardown of a previous session, so it
+/// gets a few short retries of its own, capped well below `RetryConfig`'s.
+const SESSION_RETRY_DELAY: Duration = Duration::from_millis(500);
+const SESSION_RETRY_MAX_DELAY: Duration = Duration::from_secs(4);
+const MAX_SESSION_ATTEMPTS: u32 = 5;
+/// The SDK's own default for `close_session`.
+const DEFAULT_CLOSE_TIMEOUT_SECONDS: u64 = 30;
+const FINALIZE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Backoff before the first reconnect attempt, doubled after each failure.
+const RECONNECT_INITIAL_DELAY: Duration = Duration::from_millis(500);
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(8);
+
+fn lock<T>(mutex: &StdMutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+#[derive(Debug, Default)]
+struct StreamCounters {
+    frames_sent: AtomicU64,
+    bytes_sent: AtomicU64,
+    messages_received: AtomicU64,
+    bytes_received: AtomicU64,
+    reconnects: AtomicU64,
+    sequence: AtomicU64,
+    started_at: StdMutex<Option<Instant>>,
+}
+
+impl StreamCounters {
+    fn sent(&self, bytes: usize) {
+        self.frames_sent.fetch_add(1, Ordering::Relaxed);
+        self.bytes_sent.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    fn received(&self, bytes: usize) {
+        self.messages_received.fetch_add(1, Ordering::Relaxed);
+        self.bytes_received
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    /// Sequence number of the next delivered message, starting at 1. It lives
+    /// on the stream object, so numbering continues across reconnects.
+    fn next_sequence(&self) -> u64 {
+        self.sequence.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    fn snapshot(&self) -> StreamStats {
+        StreamStats {
+            frames_sent: self.frames_sent.load(Ordering::Relaxed),
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+            messages_received: self.messages_received.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            reconnects: self.reconnects.load(Ordering::Relaxed),
+            elapsed_seconds: lock(&self.started_at)
+                .map(|started| started.elapsed().as_secs_f64())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Whether the start handshake has completed, plus the frames sent before it
+/// did when buffering is enabled.
+#[derive(Debug, Default)]
+struct ReadyGate {
+    ready: bool,
+    queued: Option<Vec<Message>>,
+}
+
+impl ReadyGate {
+    /// Where to hold frames while the gate is closed and buffering.
+    fn pending(&mut self) -> Option<&mut Vec<Message>> {
+        if self.ready {
+            return None;
+        }
+        self.queued.as_mut()
+    }
+
+    /// Opens the gate, handing back the held frames in the order they came.
+    fn open(&mut self) -> Vec<Message> {
+        self.ready = true;
+        self.queued.as_mut().map(std::mem::take).unwrap_or_default()
+    }
+}
+
 #[pyclass]
 pub struct TranscribeStream {
     ws: Arc<Mutex<TranscribeWs>>,
-    stream: Arc<Mutex<Valved<Pin<Box<dyn Stream<Item = Utf8Bytes> + Send>>>>>,
+    stream: Arc<Mutex<Valved<FrameStream>>>,
+    valve: Arc<StdMutex<Option<Trigger>>>,
+    idle_timeout: Option<Duration>,
+    last_send: Arc<StdMutex<Instant>>,
+    watchdog: Arc<StdMutex<Option<JoinHandle<()>>>>,
+    terminal_event: Arc<StdMutex<Option<String>>>,
+    counters: Arc<StreamCounters>,
+    ready_gate: Arc<StdMutex<ReadyGate>>,
+    raw_log: Arc<RawLog>,
+    closed: Arc<AtomicBool>,
+    /// Set by `shutdown`; from then on every start, read and send fails.
+    shut_down: Arc<AtomicBool>,
+    /// Set once a stop has been requested, so the end of the socket that
+    /// follows is not mistaken for a dropped connection.
+    stopping: Arc<AtomicBool>,
+    pump: Arc<StdMutex<Option<JoinHandle<()>>>>,
+    keepalive_interval: Option<Duration>,
+    keepalive: Arc<StdMutex<Option<JoinHandle<()>>>>,
+    /// The `on_message` task, if one was started.
+    dispatcher: Arc<StdMutex<Option<JoinHandle<()>>>>,
+}
+
+/// Stops the session once no frame has been sent for `idle`, leaving an
+/// `idle_timeout` event for the reader before the stream ends.
+async fn watch_idle(
+    ws: Arc<Mutex<TranscribeWs>>,
+    valve: Arc<StdMutex<Option<Trigger>>>,
+    last_send: Arc<StdMutex<Instant>>,
+    terminal_event: Arc<StdMutex<Option<String>>>,
+    stopping: Arc<AtomicBool>,
+    idle: Duration,
+) {
+    loop {
+        let deadline = *lock(&last_send) + idle;
+        tokio::time::sleep_until(tokio::time::Instant::from_std(deadline)).await;
+        if lock(&last_send).elapsed() >= idle {
+            break;
+        }
+    }
+
+    log::info!(
+        "no frame sent for {:.1}s, stopping stream",
+        idle.as_secs_f64()
+    );
+    stopping.store(true, Ordering::Release);
+    ws.lock().await.stop();
+    let event = json!({ "type": "idle_timeout", "idle_seconds": idle.as_secs_f64() });
+    *lock(&terminal_event) = Some(event.to_string());
+    if let Some(trigger) = lock(&valve).take() {
+        trigger.cancel();
+    }
+}
+
+/// Pings the server every `interval` so proxies do not drop a quiet socket.
+/// Pings are not counted in the stats and do not reset the idle timer; the
+/// task ends at the first failed write, e.g. once the session has stopped.
+async fn keep_alive(ws: Arc<Mutex<TranscribeWs>>, interval: Duration) {
+    ping_every(interval, || {
+        let ws = ws.clone();
+        async move {
+            ws.lock()
+                .await
+                .write(Message::Ping(Default::default()))
+                .await
+        }
+    })
+    .await;
+}
+
+async fn ping_every<F, Fut, E>(interval: Duration, mut ping: F)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: std::fmt::Display,
+{
+    let mut ticker = tokio::time::interval(interval);
+    // The first tick completes immediately.
+    ticker.tick().await;
+    loop {
+        ticker.tick().await;
+        if let Err(err) = ping().await {
+            log::debug!("keepalive ping failed, stopping keepalive: {err}");
+            break;
+        }
+    }
+}
+
+fn abort_task(task: &StdMutex<Option<JoinHandle<()>>>) {
+    if let Some(task) = lock(task).take() {
+        task.abort();
+    }
+}
+
+/// What the reconnect pump needs to replace a dropped socket.
+struct Reconnect {
+    session_id: String,
+    ws: Arc<Mutex<TranscribeWs>>,
+    stopping: Arc<AtomicBool>,
+    counters: Arc<StreamCounters>,
+    max_attempts: u32,
+}
+
+/// Opens a fresh socket for the same session, with exponential backoff.
+/// Returns the attempt that succeeded, or the attempts made and last error.
+async fn reopen_socket(reconnect: &Reconnect) -> Result<(u32, FrameStream), (u32, String)> {
+    let mut delay = RECONNECT_INITIAL_DELAY;
+    let mut last_error = String::new();
+    for attempt in 1..=reconnect.max_attempts {
+        tokio::time::sleep(delay).await;
+        if reconnect.stopping.load(Ordering::Acquire) {
+            return Err((attempt - 1, "stream was stopped".into()));
+        }
+        let mut ws = reconnect.ws.lock().await;
+        ws.stop();
+        *ws = TranscribeWs::new(&reconnect.session_id);
+        match ws.subscribe() {
+            Ok(frames) => match ws.start().await {
+                Ok(()) => return Ok((attempt, Box::pin(frames) as FrameStream)),
+                Err(err) => last_error = err.to_string(),
+            },
+            Err(err) => last_error = err.to_string(),
+        }
+        drop(ws);
+        delay = (delay * 2).min(RECONNECT_MAX_DELAY);
+    }
+    Err((reconnect.max_attempts, last_error))
+}
+
+/// Forwards frames to the reader and replaces the socket whenever it ends
+/// without a stop having been requested. Each replacement is announced with
+/// a `reconnected` event; giving up ends the stream after a
+/// `reconnect_failed` event.
+async fn pump_with_reconnect(
+    mut frames: FrameStream,
+    reconnect: Reconnect,
+    frames_tx: mpsc::Sender<Utf8Bytes>,
+) {
+    loop {
+        while let Some(frame) = frames.next().await {
+            if frames_tx.send(frame).await.is_err() {
+                return;
+            }
+        }
+        if reconnect.stopping.load(Ordering::Acquire) {
+            return;
+        }
+        log::info!(
+            "stream for session '{}' dropped, reconnecting",
+            reconnect.session_id
+        );
+        let event = match reopen_socket(&reconnect).await {
+            Ok((attempt, next)) => {
+                log::info!("stream reconnected on attempt {attempt}");
+                frames = next;
+                reconnect
+                    .counters
+                    .reconnects
+                    .fetch_add(1, Ordering::Relaxed);
+                json!({ "type": "reconnected", "attempt": attempt })
+            }
+            Err((attempts, error)) => {
+                log::warn!("gave up reconnecting stream after {attempts} attempt(s): {error}");
+                let event = json!({
+                    "type": "reconnect_failed",
+                    "attempts": attempts,
+                    "error": error,
+                });
+                let _ = frames_tx.send(event.to_string().into()).await;
+                return;
+            }
+        };
+        if frames_tx.send(event.to_string().into()).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Optional file receiving a copy of every raw frame, one per line.
+type RawLog = StdMutex<Option<File>>;
+
+fn tee_raw(raw_log: &RawLog, frame: &str) {
+    let mut raw_log = lock(raw_log);
+    if let Some(file) = raw_log.as_mut()
+        && file.write_all(format!("{frame}\n").as_bytes()).is_err()
+    {
+        // A broken log must not break the stream; stop teeing instead.
+        *raw_log = None;
+    }
+}
+
+/// Next frame as text with its sequence number; once the stream has ended,
+/// yields the pending terminal event (if any) a single time.
+async fn next_sequenced(
+    stream: &Mutex<Valved<FrameStream>>,
+    terminal_event: &StdMutex<Option<String>>,
+    counters: &StreamCounters,
+    raw_log: &RawLog,
+) -> Option<(u64, String)> {
+    let mut guard = stream.lock().await;
+    let message = match guard.next().await {
+        Some(message) => {
+            counters.received(message.as_str().len());
+            tee_raw(raw_log, message.as_str());
+            message.to_string()
+        }
+        None => lock(terminal_event).take()?,
+    };
+    Some((counters.next_sequence(), message))
+}
+
+fn ensure_not_closed(closed: &AtomicBool) -> PyResult<()> {
+    if closed.load(Ordering::Acquire) {
+        return Err(StreamClosedError::new_err("stream is closed"));
+    }
+    Ok(())
+}
+
+/// Queues the frame while the gate is closed and buffering, otherwise writes
+/// it. Fails once the stream has been closed, rather than queueing for good.
+async fn write_frame(
+    ws: &Mutex<TranscribeWs>,
+    ready_gate: &StdMutex<ReadyGate>,
+    closed: &AtomicBool,
+    counters: &StreamCounters,
+    message: Message,
+) -> PyResult<()> {
+    {
+        let mut gate = lock(ready_gate);
+        ensure_not_closed(closed)?;
+        if let Some(queued) = gate.pending() {
+            queued.push(message);
+            return Ok(());
+        }
+    }
+    let size = message.len();
+    ws.lock().await.write(message).await.map_err(to_py_err)?;
+    counters.sent(size);
+    Ok(())
+}
+
+/// Writes the frames in order under a single lock of the socket. A failed
+/// write is reported with the index of the frame that did not go out.
+async fn write_frames(
+    ws: &Mutex<TranscribeWs>,
+    ready_gate: &StdMutex<ReadyGate>,
+    closed: &AtomicBool,
+    counters: &StreamCounters,
+    messages: Vec<Message>,
+) -> PyResult<()> {
+    {
+        let mut gate = lock(ready_gate);
+        ensure_not_closed(closed)?;
+        if let Some(queued) = gate.pending() {
+            queued.extend(messages);
+            return Ok(());
+        }
+    }
+    let mut ws = ws.lock().await;
+    for (index, message) in messages.into_iter().enumerate() {
+        let size = message.len();
+        if let Err(err) = ws.write(message).await {
+            return Err(to_py_err_in(err, &format!("frame {index} failed to send")));
+        }
+        counters.sent(size);
+    }
+    Ok(())
+}
+
+async fn next_message(
+    stream: &Mutex<Valved<FrameStream>>,
+    terminal_event: &StdMutex<Option<String>>,
+    counters: &StreamCounters,
+    raw_log: &RawLog,
+) -> Option<String> {
+    next_sequenced(stream, terminal_event, counters, raw_log)
+        .await
+        .map(|(_, message)| message)
+}
+
+/// Reads messages until a final result arrives and returns its utterances;
+/// a stream that ends first yields none. Fails with `TimeoutError` if neither
+/// happens within `timeout`.
+async fn await_final<F, Fut>(mut next: F, timeout: Option<Duration>) -> PyResult<Vec<Utterance>>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Option<String>>,
+{
+    let drain = async {
+        while let Some(message) = next().await {
+            let parsed = parse_message(&message);
+            if parsed.is_final {
+                return parsed.utterances;
+            }
+        }
+        Vec::new()
+    };
+    match timeout {
+        Some(duration) => tokio::time::timeout(duration, drain).await.map_err(|_| {
+            PyTimeoutError::new_err("no final transcript received before the timeout")
+        }),
+        None => Ok(drain.await),
+    }
+}
+
+/// Closes the session once, then polls its task status until the server no
+/// longer reports it as in progress, all within one overall timeout.
+async fn close_session_finalized(
+    task_id: &str,
+    token: &str,
+    timeout_seconds: Option<u64>,
+) -> PyResult<SessionEnder> {
+    let timeout = Duration::from_secs(timeout_seconds.unwrap_or(DEFAULT_CLOSE_TIMEOUT_SECONDS));
+    let deadline = tokio::time::Instant::now() + timeout;
+    let mut response = close_session(task_id, token, Some(timeout.as_secs().max(1)))
+        .await
+        .map_err(to_py_err)?;
+    response.status = wait_finalized(task_id, response.status, deadline, || async {
+        transcribe_status(Some(task_id), None, token)
+            .await
+            .map(|status| status.status)
+            .map_err(to_py_err)
+    })
+    .await?;
+    Ok(response)
+}
+
+/// Polls `status` every `FINALIZE_POLL_INTERVAL`, starting from `current`,
+/// until it is no longer pending, and returns that status.
+async fn wait_finalized<F, Fut>(
+    task_id: &str,
+    mut current: String,
+    deadline: tokio::time::Instant,
+    mut status: F,
+) -> PyResult<String>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = PyResult<String>>,
+{
+    while task_outcome(&current) == TaskOutcome::Pending {
+        if tokio::time::Instant::now() + FINALIZE_POLL_INTERVAL >= deadline {
+            return Err(PyTimeoutError::new_err(format!(
+                "session '{task_id}' was not finalized in time (last status '{current}')"
+            )));
+        }
+        tokio::time::sleep(FINALIZE_POLL_INTERVAL).await;
+        current = status().await?;
+    }
+    Ok(current)
+}
+
+/// Retries only what `with_retry` considers transient, so a rejected token or
+/// model fails on the first attempt.
+async fn create_session_with_retry(
+    model: &str,
+    token: &str,
+    max_attempts: u32,
+) -> PyResult<SessionCreator> {
+    let retry = RetryConfig::new(
+        max_attempts,
+        SESSION_RETRY_DELAY.as_secs_f64(),
+        2.0,
+        SESSION_RETRY_MAX_DELAY.as_secs_f64(),
+    )?;
+    with_retry(Some(&retry), || async move {
+        create_session(reparse(parse_model(model))?, token).await
+    })
+    .await
+    .map_err(to_py_err)
+}
+
+/// Streams `samples` through a fresh connection, pacing chunks at real time
+/// when `realtime` is set, and returns the final utterances received.
+async fn stream_samples_to_session(
+    session_id: &str,
+    samples: &[u8],
+    chunk: Duration,
+    realtime: bool,
+    finish_timeout: Duration,
+) -> PyResult<Vec<Utterance>> {
+    let mut ws = TranscribeWs::new(session_id);
+    let mut frames = ws.subscribe().map_err(to_py_err)?;
+    ws.start().await.map_err(to_py_err)?;
+
+    let collected = Arc::new(StdMutex::new(Vec::new()));
+    let (finals_tx, mut finals) = watch::channel(0u64);
+    let reader = tokio::spawn({
+        let collected = collected.clone();
+        async move {
+            while let Some(frame) = frames.next().await {
+                let parsed = parse_message(frame.as_str());
+                if parsed.is_final {
+                    lock(&collected).extend(parsed.utterances);
+                    finals_tx.send_modify(|count| *count += 1);
+                }
+            }
+        }
+    });
+
+    let chunk_bytes = ((STREAM_BYTE_RATE as f64 * chunk.as_secs_f64()) as usize).max(2) & !1;
+    for frame in samples.chunks(chunk_bytes) {
+        if let Err(err) = ws.write(Message::Binary(frame.to_vec().into())).await {
+            reader.abort();
+            ws.stop();
+            return Err(to_py_err(err));
+        }
+        if realtime {
+            tokio::time::sleep(chunk).await;
+        }
+    }
+    // The final for the last frames is still on its way; stopping first
+    // could drop it. If it does not come in time, keep whatever arrived.
+    if !next_final(&mut finals, finish_timeout).await {
+        log::debug!("no final after the last frame within {finish_timeout:?}");
+    }
+    ws.stop();
+    reader.abort();
+    Ok(std::mem::take(&mut *lock(&collected)))
+}
+
+/// Waits for the reader to count one more final than it had so far. False on
+/// timeout, or once the reader has ended without another one.
+async fn next_final(finals: &mut watch::Receiver<u64>, timeout: Duration) -> bool {
+    let seen = *finals.borrow_and_update();
+    let arrived = finals.wait_for(|count| *count > seen);
+    matches!(tokio::time::timeout(timeout, arrived).await, Ok(Ok(_)))
+}
+
+/// The loop behind `on_message`. The first exception raised by the callback
+/// stops it and is what the dispatch reports.
+struct Dispatch {
+    callback: Py<PyAny>,
+    stream: Arc<Mutex<Valved<FrameStream>>>,
+    terminal_event: Arc<StdMutex<Option<String>>>,
+    counters: Arc<StreamCounters>,
+    raw_log: Arc<RawLog>,
+    shut_down: Arc<AtomicBool>,
+    dispatched: Arc<AtomicU64>,
+}
+
+impl Dispatch {
+    async fn run(&self) -> PyResult<u64> {
+        while let Some(message) = next_message(
+            &self.stream,
+            &self.terminal_event,
+            &self.counters,
+            &self.raw_log,
+        )
+        .await
+        {
+            ensure_open(&self.shut_down)?;
+            let pending = Python::attach(|py| -> PyResult<_> {
+                let result = self.callback.bind(py).call1((message,))?;
+                if result.hasattr("__await__")? {
+                    Ok(Some(pyo3_async_runtimes::tokio::into_future(result)?))
+                } else {
+                    Ok(None)
+                }
+            })?;
+            if let Some(pending) = pending {
+                pending.await?;
+            }
+            self.dispatched.fetch_add(1, Ordering::Relaxed);
+        }
+        Ok(self.dispatched.load(Ordering::Relaxed))
+    }
+}
+
+fn ensure_open(shut_down: &AtomicBool) -> PyResult<()> {
+    if shut_down.load(Ordering::Acquire) {
+        return Err(StreamClosedError::new_err("stream has been shut down"));
+    }
+    Ok(())
 }
 
 #[pymethods]
 impl TranscribeStream {
     #[new]
-    pub fn new(session_id: String) -> PyResult<Self> {
+    #[pyo3(signature = (
+        session_id,
+        *,
+        idle_timeout=None,
+        buffer_until_ready=false,
+        raw_log=None,
+        auto_reconnect=false,
+        max_reconnect_attempts=3,
+        keepalive_interval_seconds=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        session_id: String,
+        idle_timeout: Option<f64>,
+        buffer_until_ready: bool,
+        raw_log: Option<String>,
+        auto_reconnect: bool,
+        max_reconnect_attempts: u32,
+        keepalive_interval_seconds: Option<f64>,
+    ) -> PyResult<Self> {
+        let idle_timeout = idle_timeout
+            .map(|value| parse_interval(value, "idle_timeout"))
+            .transpose()?;
+        let keepalive_interval = keepalive_interval_seconds
+            .map(|value| parse_seconds(value, "keepalive_interval_seconds"))
+            .transpose()?
+            .filter(|interval| !interval.is_zero());
+        if keepalive_interval_seconds.is_some() && keepalive_interval.is_none() {
+            return Err(invalid_input("keepalive_interval_seconds must be positive"));
+        }
+        let raw_log = raw_log
+            .map(|path| {
+                OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&path)
+                    .map_err(|err| invalid_input(format!("cannot open '{path}': {err}")))
+            })
+            .transpose()?;
+        if auto_reconnect && max_reconnect_attempts == 0 {
+            return Err(invalid_input(
+                "max_reconnect_attempts must allow at least one attempt",
+            ));
+        }
         let mut ws = TranscribeWs::new(&session_id);
-        let stream = ws.subscribe()?;
+        let frames = Box::pin(ws.subscribe().map_err(to_py_err)?) as FrameStream;
+        let ws = Arc::new(Mutex::new(ws));
+        let stopping = Arc::new(AtomicBool::new(false));
+        let counters = Arc::new(StreamCounters::default());
+
+        let (frames, pump) = if auto_reconnect {
+            let (frames_tx, frames_rx) = mpsc::channel(256);
+            let reconnect = Reconnect {
+                session_id,
+                ws: ws.clone(),
+                stopping: stopping.clone(),
+                counters: counters.clone(),
+                max_attempts: max_reconnect_attempts,
+            };
+            let pump = pyo3_async_runtimes::tokio::get_runtime()
+                .spawn(pump_with_reconnect(frames, reconnect, frames_tx));
+            (
+                Box::pin(ReceiverStream::new(frames_rx)) as FrameStream,
+                Some(pump),
+            )
+        } else {
+            (frames, None)
+        };
+        let (trigger, stream) = Valved::new(frames);
         Ok(Self {
-            ws: Arc::new(Mutex::new(ws)),
+            ws,
             stream: Arc::new(Mutex::new(stream)),
+            valve: Arc::new(StdMutex::new(Some(trigger))),
+            idle_timeout,
+            last_send: Arc::new(StdMutex::new(Instant::now())),
+            watchdog: Arc::new(StdMutex::new(None)),
+            terminal_event: Arc::new(StdMutex::new(None)),
+            counters,
+            ready_gate: Arc::new(StdMutex::new(ReadyGate {
+                ready: false,
+                queued: buffer_until_ready.then(Vec::new),
+            })),
+            raw_log: Arc::new(StdMutex::new(raw_log)),
+            closed: Arc::new(AtomicBool::new(false)),
+            shut_down: Arc::new(AtomicBool::new(false)),
+            stopping,
+            pump: Arc::new(StdMutex::new(pump)),
+            keepalive_interval,
+            keepalive: Arc::new(StdMutex::new(None)),
+            dispatcher: Arc::new(StdMutex::new(None)),
         })
     }
 
     #[staticmethod]
+    #[pyo3(signature = (model, token, *, max_attempts=3))]
     pub fn create_session<'py>(
         py: Python<'py>,
         model: Bound<'py, PyAny>,
         token: Bound<'py, PyAny>,
+        max_attempts: u32,
     ) -> PyResult<Bound<'py, PyAny>> {
         let model: String = model.extract()?;
-        let model = parse_model(&model)?;
+        parse_model(&model)?;
         let token: String = token.extract()?;
+        if !(1..=MAX_SESSION_ATTEMPTS).contains(&max_attempts) {
+            return Err(invalid_input(format!(
+                "max_attempts must be between 1 and {MAX_SESSION_ATTEMPTS}"
+            )));
+        }
 
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let response = create_session(model, &token).await?;
-            Ok(SessionCreateResult::from(response))
-        })
+        pyo3_async_runtimes::tokio::future_into_py(
+            py,
+            within_timeout(None, "create_session", async move {
+                let response = create_session_with_retry(&model, &token, max_attempts).await?;
+                Ok(SessionCreateResult::from(response))
+            }),
+        )
     }
 
     #[staticmethod]
+    #[pyo3(signature = (task_id, token, timeout_seconds=None, *, wait_finalized=false))]
     pub fn close_session<'py>(
         py: Python<'py>,
         task_id: Bound<'py, PyAny>,
         token: Bound<'py, PyAny>,
         timeout_seconds: Option<u64>,
+        wait_finalized: bool,
     ) -> PyResult<Bound<'py, PyAny>> {
         let task_id: String = task_id.extract()?;
         let token: String = token.extract()?;
 
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let response = close_session(&task_id, &token, timeout_seconds).await?;
-            Ok(SessionCloseResult::from(response))
-        })
+        pyo3_async_runtimes::tokio::future_into_py(
+            py,
+            within_timeout(None, "close_session", async move {
+                let response = if wait_finalized {
+                    close_session_finalized(&task_id, &token, timeout_seconds).await?
+                } else {
+                    close_session(&task_id, &token, timeout_seconds)
+                        .await
+                        .map_err(to_py_err)?
+                };
+                Ok(SessionCloseResult::from(response))
+            }),
+        )
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (
+        path,
+        model,
+        token,
+        *,
+        chunk_seconds=0.2,
+        realtime=true,
+        finish_timeout=30.0
+    ))]
+    pub fn transcribe_file_streaming<'py>(
+        py: Python<'py>,
+        path: Bound<'py, PyAny>,
+        model: Bound<'py, PyAny>,
+        token: Bound<'py, PyAny>,
+        chunk_seconds: f64,
+        realtime: bool,
+        finish_timeout: f64,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let path: String = path.extract()?;
+        let model: String = model.extract()?;
+        parse_model(&model)?;
+        let token: String = token.extract()?;
+        let chunk = parse_seconds(chunk_seconds, "chunk_seconds")?;
+        if chunk.is_zero() {
+            return Err(invalid_input("chunk_seconds must be positive"));
+        }
+        let finish_timeout = parse_seconds(finish_timeout, "finish_timeout")?;
+
+        let streaming = async move {
+            let data = tokio::fs::read(&path).await?;
+            let samples = stream_samples(&path, &data)?;
+
+            let session = create_session_with_retry(&model, &token, 3).await?;
+            let streamed = stream_samples_to_session(
+                &session.session_id,
+                samples,
+                chunk,
+                realtime,
+                finish_timeout,
+            )
+            .await;
+            let closed = close_session(&session.task_id, &token, None).await;
+
+            let utterances = streamed?;
+            closed.map_err(to_py_err)?;
+            PyResult::Ok(
+                utterances
+                    .into_iter()
+                    .map(UtterancePayload::from)
+                    .collect::<Vec<_>>(),
+            )
+        };
+        pyo3_async_runtimes::tokio::future_into_py(
+            py,
+            within_timeout(None, "transcribe_file_streaming", streaming),
+        )
     }
 
-    pub fn start<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+    #[pyo3(signature = (timeout=None))]
+    pub fn start<'py>(&self, py: Python<'py>, timeout: Option<f64>) -> PyResult<Bound<'py, PyAny>> {
+        ensure_open(&self.shut_down)?;
+        let timeout = timeout
+            .map(|seconds| parse_seconds(seconds, "timeout"))
+            .transpose()?;
         let ws = self.ws.clone();
-        // let stream = self.stream.clone();
+        let valve = self.valve.clone();
+        let last_send = self.last_send.clone();
+        let watchdog = self.watchdog.clone();
+        let terminal_event = self.terminal_event.clone();
+        let idle_timeout = self.idle_timeout;
+        let counters = self.counters.clone();
+        let ready_gate = self.ready_gate.clone();
+        let stopping = self.stopping.clone();
+        let keepalive_interval = self.keepalive_interval;
+        let keepalive = self.keepalive.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let mut ws_guard = ws.lock().await;
-            ws_guard.start().await?;
-            // let mut stream_guard = stream.lock().await;
-            // let stream = ws_guard.subscribe().await?;
-            // *stream_guard = Some(stream);
+            // A timed-out earlier attempt left this set; a retried start must
+            // be able to reconnect again.
+            stopping.store(false, Ordering::Release);
+            let started = match timeout {
+                Some(duration) => match tokio::time::timeout(duration, ws_guard.start()).await {
+                    Ok(started) => started,
+                    Err(_) => {
+                        // Drop whatever the handshake left half-open.
+                        log::warn!("stream start handshake timed out");
+                        stopping.store(true, Ordering::Release);
+                        ws_guard.stop();
+                        return Err(DianyaStreamError::new_err(format!(
+                            "stream start handshake did not complete within {}s",
+                            duration.as_secs_f64()
+                        )));
+                    }
+                },
+                None => ws_guard.start().await,
+            };
+            if let Err(err) = started {
+                log::warn!("stream start failed: {err}");
+                stopping.store(true, Ordering::Release);
+                ws_guard.stop();
+                return Err(to_py_err(err));
+            }
+            log::debug!("stream started");
+            *lock(&counters.started_at) = Some(Instant::now());
+            // Flush while still holding the socket so later sends queue behind.
+            let queued = lock(&ready_gate).open();
+            for message in queued {
+                let size = message.len();
+                ws_guard.write(message).await.map_err(to_py_err)?;
+                counters.sent(size);
+            }
+            drop(ws_guard);
+
+            if let Some(interval) = keepalive_interval {
+                let task = tokio::spawn(keep_alive(ws.clone(), interval));
+                if let Some(previous) = lock(&keepalive).replace(task) {
+                    previous.abort();
+                }
+            }
+            if let Some(idle) = idle_timeout {
+                *lock(&last_send) = Instant::now();
+                let task = tokio::spawn(watch_idle(
+                    ws,
+                    valve,
+                    last_send,
+                    terminal_event,
+                    stopping,
+                    idle,
+                ));
+                if let Some(previous) = lock(&watchdog).replace(task) {
+                    previous.abort();
+                }
+            }
             Ok(())
         })
     }
 
     pub fn stop<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         let ws = self.ws.clone();
+        let watchdog = self.watchdog.clone();
+        let stopping = self.stopping.clone();
+        let keepalive = self.keepalive.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            abort_task(&watchdog);
+            abort_task(&keepalive);
+            log::debug!("stream stop requested");
+            stopping.store(true, Ordering::Release);
             let mut guard = ws.lock().await;
             guard.stop();
             Ok(())
         })
     }
 
+    /// Unlike `stop`, also ends pending reads, drops queued frames and closes
+    /// the raw log. Only the first call does any work.
+    pub fn aclose<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let closed = self.closed.clone();
+        let ws = self.ws.clone();
+        let watchdog = self.watchdog.clone();
+        let valve = self.valve.clone();
+        let ready_gate = self.ready_gate.clone();
+        let raw_log = self.raw_log.clone();
+        let stopping = self.stopping.clone();
+        let pump = self.pump.clone();
+        let keepalive = self.keepalive.clone();
+        let dispatcher = self.dispatcher.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            if closed.swap(true, Ordering::AcqRel) {
+                return Ok(());
+            }
+            log::debug!("stream closing");
+            abort_task(&watchdog);
+            stopping.store(true, Ordering::Release);
+            abort_task(&pump);
+            abort_task(&keepalive);
+            abort_task(&dispatcher);
+            ws.lock().await.stop();
+            if let Some(trigger) = lock(&valve).take() {
+                trigger.cancel();
+            }
+            {
+                let mut gate = lock(&ready_gate);
+                gate.ready = false;
+                gate.queued = None;
+            }
+            *lock(&raw_log) = None;
+            Ok(())
+        })
+    }
+
+    /// `aclose`, after which the stream is terminal: starting, reading or
+    /// sending raises `StreamClosedError` instead of quietly ending. Reads
+    /// already waiting return `None`.
+    pub fn shutdown<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        log::debug!("stream shut down");
+        self.shut_down.store(true, Ordering::Release);
+        self.aclose(py)
+    }
+
+    pub fn __aenter__<'py>(slf: Bound<'py, Self>) -> PyResult<Bound<'py, PyAny>> {
+        let py = slf.py();
+        let start = pyo3_async_runtimes::tokio::into_future(slf.borrow().start(py, None)?)?;
+        let stream = slf.unbind();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            start.await?;
+            Ok(stream)
+        })
+    }
+
+    /// Closes via `aclose`. While an exception is propagating, a failure to
+    /// close is dropped so it cannot mask the original error.
+    #[pyo3(signature = (exc_type, _exc_value, _traceback))]
+    pub fn __aexit__<'py>(
+        &self,
+        py: Python<'py>,
+        exc_type: Option<Bound<'py, PyAny>>,
+        _exc_value: Option<Bound<'py, PyAny>>,
+        _traceback: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let unwinding = exc_type.is_some();
+        let close = pyo3_async_runtimes::tokio::into_future(self.aclose(py)?)?;
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            match close.await {
+                Err(_) if unwinding => {}
+                result => {
+                    result?;
+                }
+            }
+            Ok(false)
+        })
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
     pub fn send_text<'py>(
         &self,
         py: Python<'py>,
         message: Bound<'py, PyAny>,
     ) -> PyResult<Bound<'py, PyAny>> {
+        ensure_open(&self.shut_down)?;
         let ws = self.ws.clone();
         let payload: String = message.extract()?;
+        *lock(&self.last_send) = Instant::now();
+        let counters = self.counters.clone();
+        let ready_gate = self.ready_gate.clone();
+        let closed = self.closed.clone();
 
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let mut guard = ws.lock().await;
-            guard.write(Message::Text(payload.into())).await?;
-            Ok(())
+            let message = Message::Text(payload.into());
+            write_frame(&ws, &ready_gate, &closed, &counters, message).await
         })
     }
 
@@ -103,44 +1012,499 @@ impl TranscribeStream {
         py: Python<'py>,
         data: Bound<'py, PyAny>,
     ) -> PyResult<Bound<'py, PyAny>> {
+        ensure_open(&self.shut_down)?;
         let ws = self.ws.clone();
-        let payload: Vec<u8> = match data.extract() {
+        let payload = match extract_bytes(&data) {
             Ok(bytes) => bytes,
             Err(err) => {
-                return Err(Error::InvalidInput(format!("data must be bytes-like: {err}")).into());
+                return Err(invalid_input(format!("data must be bytes-like: {err}")));
             }
         };
+        *lock(&self.last_send) = Instant::now();
+        let counters = self.counters.clone();
+        let ready_gate = self.ready_gate.clone();
+        let closed = self.closed.clone();
 
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let mut guard = ws.lock().await;
-            guard.write(Message::Binary(payload.into())).await?;
-            Ok(())
+            let message = Message::Binary(payload.into());
+            write_frame(&ws, &ready_gate, &closed, &counters, message).await
+        })
+    }
+
+    pub fn send_bytes_batch<'py>(
+        &self,
+        py: Python<'py>,
+        frames: Vec<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        ensure_open(&self.shut_down)?;
+        let ws = self.ws.clone();
+        let mut messages = Vec::with_capacity(frames.len());
+        for (index, frame) in frames.iter().enumerate() {
+            let payload = extract_bytes(frame)
+                .map_err(|err| invalid_input(format!("frame {index} must be bytes-like: {err}")))?;
+            messages.push(Message::Binary(payload.into()));
+        }
+        *lock(&self.last_send) = Instant::now();
+        let counters = self.counters.clone();
+        let ready_gate = self.ready_gate.clone();
+        let closed = self.closed.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            write_frames(&ws, &ready_gate, &closed, &counters, messages).await
+        })
+    }
+
+    /// Sends every chunk of an async iterable as a binary frame. Each send is
+    /// awaited before the next chunk is requested, so a slow connection holds
+    /// back the producer instead of piling up frames.
+    #[pyo3(signature = (source, *, pace_seconds=None))]
+    pub fn send_from_async_iter<'py>(
+        &self,
+        py: Python<'py>,
+        source: Bound<'py, PyAny>,
+        pace_seconds: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        ensure_open(&self.shut_down)?;
+        let pace = pace_seconds
+            .map(|value| parse_seconds(value, "pace_seconds"))
+            .transpose()?;
+        let iterator = source.call_method0("__aiter__")?.unbind();
+        let ws = self.ws.clone();
+        let last_send = self.last_send.clone();
+        let counters = self.counters.clone();
+        let ready_gate = self.ready_gate.clone();
+        let closed = self.closed.clone();
+        let shut_down = self.shut_down.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut sent: u64 = 0;
+            loop {
+                ensure_open(&shut_down)?;
+                let next = Python::attach(|py| {
+                    pyo3_async_runtimes::tokio::into_future(
+                        iterator.bind(py).call_method0("__anext__")?,
+                    )
+                })?;
+                let chunk = match next.await {
+                    Ok(chunk) => chunk,
+                    Err(err) => {
+                        if Python::attach(|py| err.is_instance_of::<PyStopAsyncIteration>(py)) {
+                            break;
+                        }
+                        return Err(err);
+                    }
+                };
+                let payload =
+                    Python::attach(|py| extract_bytes(chunk.bind(py))).map_err(|err| {
+                        invalid_input(format!("chunk {sent} must be bytes-like: {err}"))
+                    })?;
+                *lock(&last_send) = Instant::now();
+                let message = Message::Binary(payload.into());
+                write_frame(&ws, &ready_gate, &closed, &counters, message).await?;
+                sent += 1;
+                if let Some(pace) = pace {
+                    tokio::time::sleep(pace).await;
+                }
+            }
+            Ok(sent)
         })
     }
 
+    pub fn is_ready(&self) -> bool {
+        lock(&self.ready_gate).ready
+    }
+
+    pub fn stats(&self) -> StreamStats {
+        self.counters.snapshot()
+    }
+
     pub fn read_next<'py>(
         &self,
         py: Python<'py>,
         timeout: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
+        ensure_open(&self.shut_down)?;
         let stream = self.stream.clone();
+        let terminal_event = self.terminal_event.clone();
+        let counters = self.counters.clone();
+        let raw_log = self.raw_log.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let duration = timeout
                 .filter(|value| *value >= 0.0)
                 .map(Duration::from_secs_f64);
 
-            let mut guard = stream.lock().await;
+            let next = next_message(&stream, &terminal_event, &counters, &raw_log);
             if let Some(duration) = duration {
-                match tokio::time::timeout(duration, guard.next()).await {
-                    Ok(Some(message)) => Ok(Some(message.to_string())),
-                    _ => Ok(None),
-                }
+                Ok(tokio::time::timeout(duration, next).await.unwrap_or(None))
             } else {
-                match guard.next().await {
-                    Some(message) => Ok(Some(message.to_string())),
-                    None => Ok(None),
+                Ok(next.await)
+            }
+        })
+    }
+
+    /// `read_next`, with the frame decoded into a `StreamMessage`.
+    #[pyo3(signature = (timeout=None))]
+    pub fn read_next_parsed<'py>(
+        &self,
+        py: Python<'py>,
+        timeout: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        ensure_open(&self.shut_down)?;
+        let stream = self.stream.clone();
+        let terminal_event = self.terminal_event.clone();
+        let counters = self.counters.clone();
+        let raw_log = self.raw_log.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let duration = timeout
+                .filter(|value| *value >= 0.0)
+                .map(Duration::from_secs_f64);
+
+            let next = next_message(&stream, &terminal_event, &counters, &raw_log);
+            let message = match duration {
+                Some(duration) => tokio::time::timeout(duration, next).await.unwrap_or(None),
+                None => next.await,
+            };
+            Ok(message.map(StreamMessage::from))
+        })
+    }
+
+    pub fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Same source as `read_next`, without a timeout; the end of the stream
+    /// becomes `StopAsyncIteration`.
+    pub fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        ensure_open(&self.shut_down)?;
+        let stream = self.stream.clone();
+        let terminal_event = self.terminal_event.clone();
+        let counters = self.counters.clone();
+        let raw_log = self.raw_log.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            next_message(&stream, &terminal_event, &counters, &raw_log)
+                .await
+                .ok_or_else(|| PyStopAsyncIteration::new_err("stream ended"))
+        })
+    }
+
+    #[pyo3(signature = (timeout=None))]
+    pub fn read_next_with_seq<'py>(
+        &self,
+        py: Python<'py>,
+        timeout: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        ensure_open(&self.shut_down)?;
+        let stream = self.stream.clone();
+        let terminal_event = self.terminal_event.clone();
+        let counters = self.counters.clone();
+        let raw_log = self.raw_log.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let duration = timeout
+                .filter(|value| *value >= 0.0)
+                .map(Duration::from_secs_f64);
+
+            let next = next_sequenced(&stream, &terminal_event, &counters, &raw_log);
+            if let Some(duration) = duration {
+                Ok(tokio::time::timeout(duration, next).await.unwrap_or(None))
+            } else {
+                Ok(next.await)
+            }
+        })
+    }
+
+    /// Discards frames until one of `event_type` arrives. Returns `None` when
+    /// the stream ends first.
+    #[pyo3(signature = (event_type, timeout=None))]
+    pub fn wait_for<'py>(
+        &self,
+        py: Python<'py>,
+        event_type: String,
+        timeout: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        ensure_open(&self.shut_down)?;
+        let duration = timeout
+            .map(|value| parse_seconds(value, "timeout"))
+            .transpose()?;
+        let stream = self.stream.clone();
+        let terminal_event = self.terminal_event.clone();
+        let counters = self.counters.clone();
+        let raw_log = self.raw_log.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let wait = async {
+                while let Some(message) =
+                    next_message(&stream, &terminal_event, &counters, &raw_log).await
+                {
+                    if is_event(&message, &event_type) {
+                        return Some(message);
+                    }
                 }
+                None
+            };
+            match duration {
+                Some(duration) => tokio::time::timeout(duration, wait).await.map_err(|_| {
+                    PyTimeoutError::new_err(format!("no '{event_type}' event before the timeout"))
+                }),
+                None => Ok(wait.await),
             }
         })
     }
+
+    /// Starts a background task, owned by the stream, that hands every message
+    /// to `callback`; a later call replaces it and `aclose` cancels it. The
+    /// returned awaitable reports how the dispatch ended.
+    pub fn on_message<'py>(
+        &self,
+        py: Python<'py>,
+        callback: Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        ensure_open(&self.shut_down)?;
+        if !callback.is_callable() {
+            return Err(invalid_input("callback must be callable"));
+        }
+        // Coroutine callbacks are awaited on the caller's event loop.
+        let locals = pyo3_async_runtimes::tokio::get_current_locals(py)?;
+        let dispatch = Dispatch {
+            callback: callback.unbind(),
+            stream: self.stream.clone(),
+            terminal_event: self.terminal_event.clone(),
+            counters: self.counters.clone(),
+            raw_log: self.raw_log.clone(),
+            shut_down: self.shut_down.clone(),
+            dispatched: Arc::new(AtomicU64::new(0)),
+        };
+        let dispatched = dispatch.dispatched.clone();
+        let (done_tx, done_rx) = oneshot::channel();
+        let task = pyo3_async_runtimes::tokio::get_runtime().spawn(
+            pyo3_async_runtimes::tokio::scope(locals, async move {
+                let _ = done_tx.send(dispatch.run().await);
+            }),
+        );
+        if let Some(previous) = lock(&self.dispatcher).replace(task) {
+            previous.abort();
+        }
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            // A cancelled dispatch ends like the stream did.
+            done_rx
+                .await
+                .unwrap_or_else(|_| Ok(dispatched.load(Ordering::Relaxed)))
+        })
+    }
+
+    #[pyo3(signature = (timeout=None))]
+    pub fn finish_and_collect<'py>(
+        &self,
+        py: Python<'py>,
+        timeout: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        ensure_open(&self.shut_down)?;
+        let duration = timeout
+            .map(|value| parse_seconds(value, "timeout"))
+            .transpose()?;
+        let ws = self.ws.clone();
+        let stream = self.stream.clone();
+        let watchdog = self.watchdog.clone();
+        let terminal_event = self.terminal_event.clone();
+        let counters = self.counters.clone();
+        let raw_log = self.raw_log.clone();
+        let stopping = self.stopping.clone();
+        let keepalive = self.keepalive.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            // The socket stays up until the final has been read; stopping
+            // first could drop it on the way in.
+            let finals = await_final(
+                || next_message(&stream, &terminal_event, &counters, &raw_log),
+                duration,
+            )
+            .await;
+            abort_task(&watchdog);
+            abort_task(&keepalive);
+            stopping.store(true, Ordering::Release);
+            ws.lock().await.stop();
+            Ok(finals?
+                .into_iter()
+                .map(UtterancePayload::from)
+                .collect::<Vec<_>>())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// Statuses handed out one per poll, counting the polls made.
+    fn polls(statuses: &[&str]) -> (RefCell<Vec<String>>, RefCell<usize>) {
+        let statuses = statuses
+            .iter()
+            .rev()
+            .map(|status| status.to_string())
+            .collect();
+        (RefCell::new(statuses), RefCell::new(0))
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn pending_close_is_polled_until_finalized() {
+        let (statuses, count) = polls(&["transcribe_running", "done"]);
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(30);
+        let status = wait_finalized("task", "transcribe_pending".to_string(), deadline, || {
+            *count.borrow_mut() += 1;
+            let next = statuses.borrow_mut().pop().unwrap();
+            async move { Ok(next) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(status, "done");
+        assert_eq!(*count.borrow(), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn finalized_close_is_not_polled() {
+        let (statuses, count) = polls(&[]);
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(30);
+        let status = wait_finalized("task", "done".to_string(), deadline, || {
+            *count.borrow_mut() += 1;
+            let next = statuses.borrow_mut().pop().unwrap_or_default();
+            async move { Ok(next) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(status, "done");
+        assert_eq!(*count.borrow(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn close_that_stays_pending_times_out() {
+        Python::initialize();
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(3);
+        let result = wait_finalized(
+            "task",
+            "transcribe_running".to_string(),
+            deadline,
+            || async { Ok("transcribe_running".to_string()) },
+        )
+        .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn keepalive_pings_each_interval_until_a_write_fails() {
+        let pings = RefCell::new(Vec::new());
+        let started = tokio::time::Instant::now();
+        ping_every(Duration::from_secs(10), || {
+            pings.borrow_mut().push(started.elapsed());
+            let failed = pings.borrow().len() == 3;
+            async move { if failed { Err("closed") } else { Ok(()) } }
+        })
+        .await;
+        let seconds: Vec<_> = pings.borrow().iter().map(Duration::as_secs).collect();
+        // No ping goes out at the start, and none after the failed one.
+        assert_eq!(seconds, [10, 20, 30]);
+    }
+
+    #[tokio::test]
+    async fn stats_count_sent_and_received_frames() {
+        let counters = StreamCounters::default();
+        counters.sent(320);
+        counters.sent(160);
+        let frames =
+            tokio_stream::iter([Utf8Bytes::from_static("ab"), Utf8Bytes::from_static("你好")]);
+        let (_trigger, frames) = Valved::new(Box::pin(frames) as FrameStream);
+        let stream = Mutex::new(frames);
+        let terminal_event = StdMutex::new(Some("idle".to_string()));
+        let raw_log = StdMutex::new(None);
+
+        let mut delivered = Vec::new();
+        while let Some(message) =
+            next_sequenced(&stream, &terminal_event, &counters, &raw_log).await
+        {
+            delivered.push(message);
+        }
+        let sequences: Vec<_> = delivered.iter().map(|(sequence, _)| *sequence).collect();
+        assert_eq!(sequences, [1, 2, 3]);
+        assert_eq!(delivered[2].1, "idle");
+
+        // The terminal event is not a received frame.
+        let stats = counters.snapshot();
+        assert_eq!((stats.frames_sent, stats.bytes_sent), (2, 480));
+        assert_eq!((stats.messages_received, stats.bytes_received), (2, 8));
+        assert_eq!(stats.reconnects, 0);
+        assert_eq!(stats.elapsed_seconds, 0.0);
+    }
+
+    #[test]
+    fn ready_gate_holds_frames_until_opened() {
+        let text = |text: &'static str| Message::Text(Utf8Bytes::from_static(text));
+        let mut gate = ReadyGate {
+            ready: false,
+            queued: Some(Vec::new()),
+        };
+        gate.pending().unwrap().push(text("a"));
+        gate.pending().unwrap().extend([text("b"), text("c")]);
+        assert_eq!(gate.open(), [text("a"), text("b"), text("c")]);
+        // Once open, frames are written straight away.
+        assert!(gate.pending().is_none());
+        assert!(gate.open().is_empty());
+
+        let mut unbuffered = ReadyGate::default();
+        assert!(unbuffered.pending().is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn await_final_returns_the_first_final() {
+        let frame = |kind: &str, text: &str| {
+            let data = json!({ "start_time": 0, "end_time": 1, "text": text, "speaker": 0 });
+            json!({ "type": kind, "data": data }).to_string()
+        };
+        // Popped from the back, so the partial is read first.
+        let frames = RefCell::new(vec![
+            frame("final", "later"),
+            frame("final", "你好"),
+            frame("partial", "你"),
+        ]);
+        let next = || {
+            let popped = frames.borrow_mut().pop();
+            async move { popped }
+        };
+        let utterances = await_final(next, Some(Duration::from_secs(5)))
+            .await
+            .unwrap();
+        let texts: Vec<_> = utterances.iter().map(|u| u.text.as_str()).collect();
+        assert_eq!(texts, ["你好"]);
+        // Reading stops at the final; later frames are left unread.
+        assert_eq!(frames.borrow().len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn await_final_without_a_final() {
+        let ended = await_final(|| async { None }, None).await.unwrap();
+        assert!(ended.is_empty());
+
+        Python::initialize();
+        let silent = await_final(
+            std::future::pending::<Option<String>>,
+            Some(Duration::from_secs(5)),
+        )
+        .await;
+        assert!(silent.is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn next_final_waits_for_one_more_final() {
+        let (finals_tx, mut finals) = watch::channel(2u64);
+        let late = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_secs(3)).await;
+            finals_tx.send_modify(|count| *count += 1);
+            finals_tx
+        });
+        // Finals counted before the wait do not end it.
+        assert!(!next_final(&mut finals, Duration::from_secs(1)).await);
+        assert!(next_final(&mut finals, Duration::from_secs(5)).await);
+
+        drop(late.await.unwrap());
+        assert!(!next_final(&mut finals, Duration::from_secs(5)).await);
+    }
 }
@@ -1,38 +1,621 @@
 use crate::{
+    audio::check_upload_file,
+    capture::{CapturedExchange, DebugCapture, describe, redact_token},
+    errors::{DianyaError, TaskFailedError, http_status, invalid_input, to_py_err},
+    formats::{
+        check_export, relabel_speakers, render_srt, render_vtt, write_atomically,
+        write_utterances_csv,
+    },
     py_types::{
-        CallbackResponse, ShareLinkResponse, StatusResponse, SummaryCreateResponse,
-        TextTranslationResponse, TranscribeTranslationResponse, UploadResult,
-        UtteranceTranslationResponse,
+        CallbackResponse, ChunkedSummaryResponse, ShareLinkResponse, StatusResponse,
+        SummaryCreateResponse, TextTranslationResponse, TranscribeTranslationResponse,
+        TranslatedSummaryResponse, UploadOutcome, UploadResult, UtteranceTranslationResponse,
     },
+    retry::{RetryConfig, reparse, with_retry},
     types::{
-        extract_utterances, parse_export_format, parse_export_type, parse_language, parse_model,
+        ExportTarget, Glossary, TaskOutcome, extract_bytes, extract_utterances, from_json_lenient,
+        is_model_unavailable, is_not_found, model_to_str, normalize_name, parse_export_format,
+        parse_export_type, parse_interval, parse_language, parse_model, parse_seconds,
+        task_outcome, unique_task_ids,
     },
+    utterances::{chunk_by_chars, time_span},
 };
-use common::Error;
 use pyo3::{
+    IntoPyObjectExt,
+    exceptions::PyTimeoutError,
     prelude::*,
     types::{PyAnyMethods, PyBytes, PyString},
 };
+use serde_json::json;
+use std::{
+    collections::HashMap,
+    future::Future,
+    path::Path,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+use tokio::{
+    runtime::{Handle, RuntimeFlavor},
+    sync::{OwnedSemaphorePermit, Semaphore},
+    task::{JoinError, JoinSet},
+};
 use transcribe::{
     Utterance,
     transcribe::{
-        CallbackRequest, callback as transcribe_callback, create_summary,
-        export as transcribe_export, get_share_link, status as transcribe_status, upload,
+        CallbackRequest, ExportFormat, ExportType, TranscribeStatus,
+        callback as transcribe_callback, create_summary, export as transcribe_export,
+        get_share_link, status as transcribe_status, upload,
     },
     translate::{translate_text, translate_transcribe, translate_utterance},
 };
 
 #[pyclass]
 #[derive(Debug, Clone, Default)]
-pub struct TranscribeApi;
+pub struct TranscribeApi {
+    capture: Option<DebugCapture>,
+    limiter: Option<Arc<Semaphore>>,
+    /// Used by every method called without its own `token`.
+    token: Option<String>,
+    /// Set on the copies behind the `*_blocking` methods.
+    blocking: bool,
+}
 
-#[pymethods]
 impl TranscribeApi {
+    /// Same client, but its methods return results instead of awaitables.
+    /// Captures and the concurrency limit stay shared with `self`.
+    fn blocking(&self) -> Self {
+        Self {
+            blocking: true,
+            ..self.clone()
+        }
+    }
+
+    /// The per-call token if one was passed, otherwise the stored one.
+    fn token(&self, token: Option<Bound<'_, PyAny>>) -> PyResult<String> {
+        match token {
+            Some(token) => token.extract(),
+            None => self.token.clone().ok_or_else(|| {
+                invalid_input("no token given: pass token= or create TranscribeApi(token=...)")
+            }),
+        }
+    }
+
+    /// Runs `future` as a Python awaitable once a concurrency permit is free.
+    /// The permit is held for the whole call, polling loops included.
+    fn run_limited<'py, F, T>(&self, py: Python<'py>, future: F) -> PyResult<Bound<'py, PyAny>>
+    where
+        F: Future<Output = PyResult<T>> + Send + 'static,
+        T: for<'a> IntoPyObject<'a> + Send + 'static,
+    {
+        let limiter = self.limiter.clone();
+        self.run(py, async move {
+            let _permit = acquire_permit(limiter).await?;
+            future.await
+        })
+    }
+
+    /// Returns `future` as an awaitable, or for a blocking client runs it on
+    /// the shared tokio runtime and waits for it with the GIL released.
+    fn run<'py, F, T>(&self, py: Python<'py>, future: F) -> PyResult<Bound<'py, PyAny>>
+    where
+        F: Future<Output = PyResult<T>> + Send + 'static,
+        T: for<'a> IntoPyObject<'a> + Send + 'static,
+    {
+        if !self.blocking {
+            return pyo3_async_runtimes::tokio::future_into_py(py, future);
+        }
+        let runtime = pyo3_async_runtimes::tokio::get_runtime();
+        let current = Handle::try_current().ok();
+        // `block_in_place` is only possible on a multi-threaded runtime.
+        if current
+            .as_ref()
+            .is_some_and(|handle| handle.runtime_flavor() != RuntimeFlavor::MultiThread)
+        {
+            return Err(DianyaError::new_err(
+                "blocking methods cannot be called from a single-threaded tokio runtime; \
+                 await the async method instead",
+            ));
+        }
+        let task = runtime.spawn(future);
+        let joined = py.detach(|| match current {
+            // On a runtime thread, e.g. in a stream callback, nesting
+            // `block_on` would panic; hand the worker's other tasks off first.
+            Some(_) => tokio::task::block_in_place(|| runtime.block_on(task)),
+            None => runtime.block_on(task),
+        });
+        joined.map_err(task_panicked)??.into_bound_py_any(py)
+    }
+}
+
+async fn acquire_permit(limiter: Option<Arc<Semaphore>>) -> PyResult<Option<OwnedSemaphorePermit>> {
+    match limiter {
+        Some(limiter) => limiter
+            .acquire_owned()
+            .await
+            .map(Some)
+            .map_err(|err| DianyaError::new_err(err.to_string())),
+        None => Ok(None),
+    }
+}
+
+/// A spawned request that panicked or was cancelled instead of returning.
+fn task_panicked(err: JoinError) -> PyErr {
+    DianyaError::new_err(format!("request task failed: {err}"))
+}
+
+/// Fetches every id concurrently, each occurrence of a repeated id with its own
+/// request, and returns the statuses in input order. Each request takes its
+/// own concurrency permit.
+async fn fetch_statuses(
+    task_ids: Vec<String>,
+    token: String,
+    limiter: Option<Arc<Semaphore>>,
+) -> PyResult<Vec<StatusResponse>> {
+    let count = task_ids.len();
+    let mut requests = JoinSet::new();
+    for (index, task_id) in task_ids.into_iter().enumerate() {
+        let token = token.clone();
+        let limiter = limiter.clone();
+        requests.spawn(async move {
+            let _permit = acquire_permit(limiter).await?;
+            let status = transcribe_status(Some(&task_id), None, &token)
+                .await
+                .map_err(to_py_err)?;
+            PyResult::Ok((index, StatusResponse::from(status)))
+        });
+    }
+
+    let mut statuses = vec![None; count];
+    while let Some(joined) = requests.join_next().await {
+        let (index, status) = joined.map_err(task_panicked)??;
+        statuses[index] = Some(status);
+    }
+    Ok(statuses.into_iter().flatten().collect())
+}
+
+/// Settings shared by every file of a batch upload.
+#[derive(Clone)]
+struct BatchUpload {
+    capture: Option<DebugCapture>,
+    retry: Option<RetryConfig>,
+    transcribe_only: bool,
+    short_asr: bool,
+    model: String,
+    fallback_model: Option<String>,
+    token: String,
+}
+
+/// Uploads the files concurrently, at most `batch_limit` at a time and each
+/// under the client's own limit too. A file that fails, including one that
+/// was already rejected before the call, becomes an error outcome rather
+/// than aborting the others. Outcomes are returned in input order.
+async fn upload_files(
+    files: Vec<(String, PyResult<()>)>,
+    batch: BatchUpload,
+    batch_limit: usize,
+    limiter: Option<Arc<Semaphore>>,
+) -> PyResult<Vec<UploadOutcome>> {
+    let count = files.len();
+    let batch_limit = Arc::new(Semaphore::new(batch_limit));
+    let mut uploads = JoinSet::new();
+    for (index, (filepath, checked)) in files.into_iter().enumerate() {
+        let batch = batch.clone();
+        let batch_limit = batch_limit.clone();
+        let limiter = limiter.clone();
+        uploads.spawn(async move {
+            if let Err(err) = checked {
+                return PyResult::Ok((index, UploadOutcome::new(filepath, Err(err))));
+            }
+            let _batch_permit = acquire_permit(Some(batch_limit)).await?;
+            let _permit = acquire_permit(limiter).await?;
+            let upload = UploadAttempt {
+                capture: batch.capture.as_ref(),
+                retry: batch.retry.as_ref(),
+                filepath: &filepath,
+                transcribe_only: batch.transcribe_only,
+                short_asr: batch.short_asr,
+                token: &batch.token,
+            };
+            let result = upload
+                .run_with_fallback(&batch.model, batch.fallback_model)
+                .await;
+            Ok((index, UploadOutcome::new(filepath, result)))
+        });
+    }
+
+    let mut outcomes = vec![None; count];
+    while let Some(joined) = uploads.join_next().await {
+        let (index, outcome) = joined.map_err(task_panicked)??;
+        outcomes[index] = Some(outcome);
+    }
+    Ok(outcomes.into_iter().flatten().collect())
+}
+
+/// Every request method, including the stream's session helpers, goes
+/// through here, so this is also where calls are logged with their duration
+/// and, for failures, the HTTP status if known.
+pub(crate) async fn within_timeout<T>(
+    timeout: Option<Duration>,
+    operation: &str,
+    future: impl Future<Output = PyResult<T>>,
+) -> PyResult<T> {
+    log::debug!("{operation} started");
+    let started = Instant::now();
+    let result = match timeout {
+        Some(duration) => tokio::time::timeout(duration, future)
+            .await
+            .unwrap_or_else(|_| {
+                Err(PyTimeoutError::new_err(format!(
+                    "{operation} did not finish within {:.1} seconds",
+                    duration.as_secs_f64()
+                )))
+            }),
+        None => future.await,
+    };
+    let elapsed = started.elapsed().as_secs_f64();
+    match &result {
+        Ok(_) => log::debug!("{operation} finished in {elapsed:.3}s"),
+        Err(err) => {
+            let message = err.to_string();
+            match http_status(&message) {
+                Some(status) => {
+                    log::debug!("{operation} failed after {elapsed:.3}s (HTTP {status}): {message}")
+                }
+                None => log::debug!("{operation} failed after {elapsed:.3}s: {message}"),
+            }
+        }
+    }
+    result
+}
+
+async fn poll_translation(
+    task_id: &str,
+    language: &str,
+    token: &str,
+    interval: Duration,
+) -> PyResult<TranscribeTranslationResponse> {
+    loop {
+        let response = translate_transcribe(task_id, parse_language(language)?, token)
+            .await
+            .map_err(to_py_err)?;
+        match task_outcome(&response.status) {
+            TaskOutcome::Done => return Ok(TranscribeTranslationResponse::from(response)),
+            TaskOutcome::Failed => {
+                return Err(TaskFailedError::new_err(format!(
+                    "translation of task '{task_id}' failed with status '{}'",
+                    response.status
+                )));
+            }
+            TaskOutcome::Pending => tokio::time::sleep(interval).await,
+        }
+    }
+}
+
+async fn poll_status(task_id: &str, token: &str, interval: Duration) -> PyResult<TranscribeStatus> {
+    loop {
+        let status = transcribe_status(Some(task_id), None, token)
+            .await
+            .map_err(to_py_err)?;
+        match task_outcome(&status.status) {
+            TaskOutcome::Done => return Ok(status),
+            TaskOutcome::Failed => return Err(task_failed(task_id, &status.status)),
+            TaskOutcome::Pending => tokio::time::sleep(interval).await,
+        }
+    }
+}
+
+fn task_failed(task_id: &str, status: &str) -> PyErr {
+    TaskFailedError::new_err(format!("task '{task_id}' failed with status '{status}'"))
+}
+
+/// One upload request with everything but the model fixed, so a fallback
+/// model can be tried with identical settings.
+struct UploadAttempt<'a> {
+    capture: Option<&'a DebugCapture>,
+    retry: Option<&'a RetryConfig>,
+    filepath: &'a str,
+    transcribe_only: bool,
+    short_asr: bool,
+    token: &'a str,
+}
+
+impl UploadAttempt<'_> {
+    async fn run(&self, model: &str) -> PyResult<UploadResult> {
+        let model_name = model_to_str(parse_model(model)?);
+        log::debug!("uploading '{}' with model '{model_name}'", self.filepath);
+        let result = with_retry(self.retry, || async {
+            let model_type = reparse(parse_model(model))?;
+            upload(
+                self.filepath,
+                self.transcribe_only,
+                self.short_asr,
+                model_type,
+                self.token,
+            )
+            .await
+        })
+        .await
+        .map(|response| UploadResult::from(response).with_model(model_name));
+        if let Some(capture) = self.capture {
+            let request = json!({
+                "filepath": self.filepath,
+                "transcribe_only": self.transcribe_only,
+                "short_asr": self.short_asr,
+                "model": model,
+                "token": redact_token(self.token),
+            });
+            capture.record("transcribe_upload", request, describe(&result));
+        }
+        result.map_err(to_py_err)
+    }
+
+    async fn run_with_fallback(
+        &self,
+        model: &str,
+        fallback: Option<String>,
+    ) -> PyResult<UploadResult> {
+        match (self.run(model).await, fallback) {
+            (Err(err), Some(fallback)) if is_model_unavailable(&err.to_string()) => {
+                self.run(&fallback).await
+            }
+            (result, _) => result,
+        }
+    }
+}
+
+/// In-memory audio written to the temp directory for the path-based SDK
+/// upload. The file is removed on drop, so a cancelled or timed-out upload
+/// does not leave it behind either.
+struct StagedFile {
+    path: String,
+}
+
+impl StagedFile {
+    async fn write(filename: &str, data: &[u8]) -> PyResult<Self> {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        let unique = format!(
+            "dianyaapi-{}-{}-{filename}",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        );
+        let staged = Self {
+            path: std::env::temp_dir()
+                .join(unique)
+                .to_string_lossy()
+                .into_owned(),
+        };
+        tokio::fs::write(&staged.path, data).await?;
+        Ok(staged)
+    }
+}
+
+impl Drop for StagedFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// The summary endpoint takes no language, so the summary is created from the
+/// translated transcript to come out in the translation's language.
+async fn translate_and_summarize(
+    task_id: &str,
+    language: &str,
+    token: &str,
+    interval: Duration,
+) -> PyResult<TranslatedSummaryResponse> {
+    let translation = poll_translation(task_id, language, token, interval).await?;
+    let summary = create_summary(translation.translated_utterances(), token)
+        .await
+        .map_err(to_py_err)?;
+    let status = poll_status(&summary.task_id, token, interval).await?;
+    Ok(TranslatedSummaryResponse::new(
+        translation,
+        StatusResponse::from(status),
+    ))
+}
+
+/// Most chunk summaries created, or polled, at the same time. Fixed rather
+/// than taken from the client's limiter, whose permit the whole call holds.
+const CHUNK_CONCURRENCY: usize = 4;
+
+/// Runs `call` on every item, at most `CHUNK_CONCURRENCY` at a time, and
+/// returns the results in input order. The first failure is returned and
+/// cancels the calls still running.
+async fn for_each_chunk<I, T, F, Fut>(items: Vec<I>, call: F) -> PyResult<Vec<T>>
+where
+    T: Send + 'static,
+    F: Fn(I) -> Fut,
+    Fut: Future<Output = PyResult<T>> + Send + 'static,
+{
+    let count = items.len();
+    let limit = Arc::new(Semaphore::new(CHUNK_CONCURRENCY));
+    let mut calls = JoinSet::new();
+    for (index, item) in items.into_iter().enumerate() {
+        let limit = limit.clone();
+        let call = call(item);
+        calls.spawn(async move {
+            let _permit = acquire_permit(Some(limit)).await?;
+            PyResult::Ok((index, call.await?))
+        });
+    }
+
+    let mut results: Vec<Option<T>> = (0..count).map(|_| None).collect();
+    while let Some(joined) = calls.join_next().await {
+        let (index, result) = joined.map_err(task_panicked)??;
+        results[index] = Some(result);
+    }
+    Ok(results.into_iter().flatten().collect())
+}
+
+/// Creates one summary task per chunk and, when `combine` is set, waits for
+/// them and summarizes their summaries, each placed at its chunk's time span.
+/// Chunks are created and polled concurrently, see `for_each_chunk`.
+async fn create_chunked_summary(
+    chunks: Vec<Vec<Utterance>>,
+    token: String,
+    combine: bool,
+    interval: Duration,
+) -> PyResult<ChunkedSummaryResponse> {
+    let spans: Vec<_> = chunks
+        .iter()
+        .map(|chunk| time_span(chunk).unwrap_or_default())
+        .collect();
+    let created = for_each_chunk(chunks, |chunk| {
+        let token = token.clone();
+        async move { create_summary(chunk, &token).await.map_err(to_py_err) }
+    })
+    .await?;
+
+    let combined = if combine && created.len() > 1 {
+        let task_ids = created
+            .iter()
+            .map(|summary| summary.task_id.clone())
+            .collect();
+        let statuses = for_each_chunk(task_ids, |task_id: String| {
+            let token = token.clone();
+            async move { poll_status(&task_id, &token, interval).await }
+        })
+        .await?;
+        let partials = statuses
+            .into_iter()
+            .zip(spans)
+            .map(|(status, (start_time, end_time))| Utterance {
+                start_time,
+                end_time,
+                text: status.summary_md.or(status.overview_md).unwrap_or_default(),
+                speaker: 0,
+            })
+            .collect();
+        let combined = create_summary(partials, &token).await.map_err(to_py_err)?;
+        Some(SummaryCreateResponse::from(combined))
+    } else {
+        None
+    };
+
+    Ok(ChunkedSummaryResponse::new(
+        created
+            .into_iter()
+            .map(SummaryCreateResponse::from)
+            .collect(),
+        combined,
+    ))
+}
+
+/// Emits the client's `#[pymethods]` block. A method marked
+/// `#[blocking = name]` also gets a twin `name` with the same Python
+/// signature, which runs it on `self.blocking()` for callers without an event
+/// loop and returns the result instead of an awaitable.
+macro_rules! client_pymethods {
+    (@emit [$($out:tt)*]) => {
+        #[pymethods]
+        impl TranscribeApi {
+            $($out)*
+        }
+    };
+    (
+        @emit [$($out:tt)*]
+        #[blocking = $blocking:ident]
+        $(#[$($attr:tt)*])*
+        pub fn $name:ident<$lt:lifetime>(
+            &$self:ident,
+            $py:ident: Python<$py_lt:lifetime>,
+            $($arg:ident: $ty:ty),* $(,)?
+        ) -> PyResult<Bound<$ret_lt:lifetime, PyAny>> $body:block
+        $($rest:tt)*
+    ) => {
+        client_pymethods!(
+            @emit [
+                $($out)*
+                $(#[$($attr)*])*
+                pub fn $name<$lt>(
+                    &$self,
+                    $py: Python<$py_lt>,
+                    $($arg: $ty),*
+                ) -> PyResult<Bound<$ret_lt, PyAny>> $body
+
+                $(#[$($attr)*])*
+                pub fn $blocking<$lt>(
+                    &$self,
+                    $py: Python<$py_lt>,
+                    $($arg: $ty),*
+                ) -> PyResult<Bound<$ret_lt, PyAny>> {
+                    $self.blocking().$name($py, $($arg),*)
+                }
+            ]
+            $($rest)*
+        );
+    };
+    (@emit [$($out:tt)*] $(#[$($attr:tt)*])* pub fn $name:ident $($rest:tt)*) => {
+        client_pymethods!(@method [$($out)*] [$(#[$($attr)*])* pub fn $name] $($rest)*);
+    };
+    // Everything up to and including the body of a method without a twin.
+    (@method [$($out:tt)*] [$($head:tt)*] $body:block $($rest:tt)*) => {
+        client_pymethods!(@emit [$($out)* $($head)* $body] $($rest)*);
+    };
+    (@method [$($out:tt)*] [$($head:tt)*] $next:tt $($rest:tt)*) => {
+        client_pymethods!(@method [$($out)*] [$($head)* $next] $($rest)*);
+    };
+    ($($methods:tt)*) => {
+        client_pymethods!(@emit [] $($methods)*);
+    };
+}
+
+client_pymethods! {
     #[new]
-    pub fn new() -> Self {
-        Self
+    #[pyo3(signature = (*, token=None, debug_capture=None, max_concurrency=None))]
+    pub fn new(
+        token: Option<String>,
+        debug_capture: Option<usize>,
+        max_concurrency: Option<usize>,
+    ) -> PyResult<Self> {
+        let capture = match debug_capture {
+            Some(0) => {
+                return Err(invalid_input(
+                    "debug_capture must keep at least one exchange",
+                ));
+            }
+            Some(capacity) => Some(DebugCapture::new(capacity)),
+            None => None,
+        };
+        let limiter = match max_concurrency {
+            Some(0) => {
+                return Err(invalid_input(
+                    "max_concurrency must allow at least one request",
+                ));
+            }
+            Some(limit) => Some(Arc::new(Semaphore::new(limit))),
+            None => None,
+        };
+        Ok(Self {
+            capture,
+            limiter,
+            token,
+            blocking: false,
+        })
+    }
+
+    pub fn debug_captures(&self) -> Vec<CapturedExchange> {
+        self.capture
+            .as_ref()
+            .map(DebugCapture::snapshot)
+            .unwrap_or_default()
     }
 
+    pub fn clear_debug_captures(&self) {
+        if let Some(capture) = &self.capture {
+            capture.clear();
+        }
+    }
+
+    #[blocking = transcribe_upload_blocking]
+    #[pyo3(signature = (
+        filepath, transcribe_only, short_asr, model, token=None, *, fallback_model=None, retry=None,
+        timeout_seconds=None, skip_validation=false
+    ))]
+    #[allow(clippy::too_many_arguments)]
     pub fn transcribe_upload<'py>(
         &self,
         py: Python<'py>,
@@ -40,25 +623,190 @@ impl TranscribeApi {
         transcribe_only: bool,
         short_asr: bool,
         model: Bound<'py, PyAny>,
-        token: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        fallback_model: Option<Bound<'py, PyAny>>,
+        retry: Option<PyRef<'py, RetryConfig>>,
+        timeout_seconds: Option<f64>,
+        skip_validation: bool,
     ) -> PyResult<Bound<'py, PyAny>> {
         let filepath: String = filepath.extract()?;
-        let model: String = model.extract()?;
-        let model = parse_model(&model)?;
-        let token: String = token.extract()?;
+        if !skip_validation {
+            check_upload_file(py, &filepath)?;
+        }
+        let model_str: String = model.extract()?;
+        parse_model(&model_str)?;
+        let fallback_str = match fallback_model {
+            Some(value) => {
+                let value: String = value.extract()?;
+                parse_model(&value)?;
+                Some(value)
+            }
+            None => None,
+        };
+        let token = self.token(token)?;
+        let capture = self.capture.clone();
+        let retry = retry.as_deref().cloned();
 
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let response = upload(&filepath, transcribe_only, short_asr, model, &token).await?;
-            Ok(UploadResult::from(response))
-        })
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+
+        self.run_limited(
+            py,
+            within_timeout(timeout, "transcribe_upload", async move {
+                let upload = UploadAttempt {
+                    capture: capture.as_ref(),
+                    retry: retry.as_ref(),
+                    filepath: &filepath,
+                    transcribe_only,
+                    short_asr,
+                    token: &token,
+                };
+                upload.run_with_fallback(&model_str, fallback_str).await
+            }),
+        )
+    }
+
+    #[blocking = transcribe_upload_many_blocking]
+    /// Not `run_limited`, for the same reason as `transcribe_status_many`:
+    /// every file takes its own permit.
+    #[pyo3(signature = (
+        filepaths, transcribe_only, short_asr, model, token=None, *, max_concurrency=4,
+        fallback_model=None, retry=None, skip_validation=false
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn transcribe_upload_many<'py>(
+        &self,
+        py: Python<'py>,
+        filepaths: Vec<String>,
+        transcribe_only: bool,
+        short_asr: bool,
+        model: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        max_concurrency: usize,
+        fallback_model: Option<Bound<'py, PyAny>>,
+        retry: Option<PyRef<'py, RetryConfig>>,
+        skip_validation: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if max_concurrency == 0 {
+            return Err(invalid_input(
+                "max_concurrency must allow at least one upload",
+            ));
+        }
+        let model_str: String = model.extract()?;
+        parse_model(&model_str)?;
+        let fallback_str = match fallback_model {
+            Some(value) => {
+                let value: String = value.extract()?;
+                parse_model(&value)?;
+                Some(value)
+            }
+            None => None,
+        };
+        let batch = BatchUpload {
+            capture: self.capture.clone(),
+            retry: retry.as_deref().cloned(),
+            transcribe_only,
+            short_asr,
+            model: model_str,
+            fallback_model: fallback_str,
+            token: self.token(token)?,
+        };
+        let files = filepaths
+            .into_iter()
+            .map(|filepath| {
+                let checked = if skip_validation {
+                    Ok(())
+                } else {
+                    check_upload_file(py, &filepath)
+                };
+                (filepath, checked)
+            })
+            .collect();
+        let limiter = self.limiter.clone();
+
+        let uploads = upload_files(files, batch, max_concurrency, limiter);
+        self.run(py, within_timeout(None, "transcribe_upload_many", uploads))
+    }
+
+    #[blocking = transcribe_upload_from_bytes_blocking]
+    /// The SDK only uploads from a path, so the data is staged in a temporary
+    /// file named after `filename` for the duration of the upload.
+    #[pyo3(signature = (
+        data, filename, transcribe_only, short_asr, model, token=None, *, fallback_model=None,
+        retry=None, timeout_seconds=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn transcribe_upload_from_bytes<'py>(
+        &self,
+        py: Python<'py>,
+        data: Bound<'py, PyAny>,
+        filename: String,
+        transcribe_only: bool,
+        short_asr: bool,
+        model: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        fallback_model: Option<Bound<'py, PyAny>>,
+        retry: Option<PyRef<'py, RetryConfig>>,
+        timeout_seconds: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let data = extract_bytes(&data)
+            .map_err(|err| invalid_input(format!("data must be bytes-like: {err}")))?;
+        if filename.is_empty() || Path::new(&filename).file_name() != Some(filename.as_ref()) {
+            return Err(invalid_input(format!(
+                "filename must be a bare file name such as 'audio.wav', got '{filename}'"
+            )));
+        }
+        let model_str: String = model.extract()?;
+        parse_model(&model_str)?;
+        let fallback_str = match fallback_model {
+            Some(value) => {
+                let value: String = value.extract()?;
+                parse_model(&value)?;
+                Some(value)
+            }
+            None => None,
+        };
+        let token = self.token(token)?;
+        let capture = self.capture.clone();
+        let retry = retry.as_deref().cloned();
+
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+
+        self.run_limited(
+            py,
+            within_timeout(timeout, "transcribe_upload_from_bytes", async move {
+                let staged = StagedFile::write(&filename, &data).await?;
+                let upload = UploadAttempt {
+                    capture: capture.as_ref(),
+                    retry: retry.as_ref(),
+                    filepath: &staged.path,
+                    transcribe_only,
+                    short_asr,
+                    token: &token,
+                };
+                upload.run_with_fallback(&model_str, fallback_str).await
+            }),
+        )
     }
 
+    #[blocking = transcribe_status_blocking]
+    #[pyo3(signature = (
+        task_id=None, share_id=None, token=None, *, normalize_speakers=false, retry=None,
+        timeout_seconds=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
     pub fn transcribe_status<'py>(
         &self,
         py: Python<'py>,
         task_id: Option<Bound<'py, PyAny>>,
         share_id: Option<Bound<'py, PyAny>>,
-        token: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        normalize_speakers: bool,
+        retry: Option<PyRef<'py, RetryConfig>>,
+        timeout_seconds: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let task_id_str = if let Some(value) = task_id {
             Some(value.extract::<String>()?)
@@ -71,148 +819,747 @@ impl TranscribeApi {
             None
         };
 
-        let token: String = token.extract()?;
+        let token = self.token(token)?;
+        let capture = self.capture.clone();
+        let retry = retry.as_deref().cloned();
 
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let response =
-                transcribe_status(task_id_str.as_deref(), share_id_str.as_deref(), &token).await?;
-            Ok(StatusResponse::from(response))
-        })
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+
+        self.run_limited(
+            py,
+            within_timeout(timeout, "transcribe_status", async move {
+                let result = with_retry(retry.as_ref(), || {
+                    transcribe_status(task_id_str.as_deref(), share_id_str.as_deref(), &token)
+                })
+                .await
+                .map(StatusResponse::from);
+                if let Some(capture) = &capture {
+                    let request = json!({
+                        "task_id": task_id_str,
+                        "share_id": share_id_str,
+                        "token": redact_token(&token),
+                    });
+                    capture.record("transcribe_status", request, describe(&result));
+                }
+                let mut response = result.map_err(to_py_err)?;
+                if normalize_speakers {
+                    response.normalize_speakers();
+                }
+                Ok(response)
+            }),
+        )
     }
 
-    pub fn transcribe_callback<'py>(
+    #[blocking = task_exists_blocking]
+    #[pyo3(signature = (task_id, *, token=None, timeout_seconds=None))]
+    pub fn task_exists<'py>(
         &self,
         py: Python<'py>,
-        request: Bound<'py, PyAny>,
-        token: Bound<'py, PyAny>,
+        task_id: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        timeout_seconds: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let token: String = token.extract()?;
-        let payload: CallbackRequest = if request.is_instance_of::<PyBytes>()
-            || request.is_instance_of::<PyString>()
-        {
-            let text: String = request.extract()?;
-            match serde_json::from_str(&text) {
-                Ok(value) => value,
-                Err(err) => {
-                    return Err(
-                        Error::InvalidInput(format!("invalid callback payload: {err}")).into(),
-                    );
+        let task_id: String = task_id.extract()?;
+        let token = self.token(token)?;
+
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+
+        self.run_limited(
+            py,
+            within_timeout(timeout, "task_exists", async move {
+                match transcribe_status(Some(&task_id), None, &token).await {
+                    Ok(_) => Ok(true),
+                    Err(err) if is_not_found(&err.to_string()) => Ok(false),
+                    Err(err) => Err(to_py_err(err)),
+                }
+            }),
+        )
+    }
+
+    #[blocking = transcribe_wait_until_done_blocking]
+    #[pyo3(signature = (task_id, *, token=None, poll_interval_seconds=2.0, timeout_seconds=None))]
+    pub fn transcribe_wait_until_done<'py>(
+        &self,
+        py: Python<'py>,
+        task_id: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        poll_interval_seconds: f64,
+        timeout_seconds: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let interval = parse_interval(poll_interval_seconds, "poll_interval_seconds")?;
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+        // A status that is already terminal is answered without a request.
+        let task_id: String = match task_id.cast::<StatusResponse>() {
+            Ok(status) => {
+                let status = status.borrow();
+                let task_id = status.task_id().unwrap_or_default().to_string();
+                match task_outcome(status.status()) {
+                    TaskOutcome::Done => {
+                        let status = status.clone();
+                        return self.run(py, async move { Ok(status) });
+                    }
+                    TaskOutcome::Failed => return Err(task_failed(&task_id, status.status())),
+                    TaskOutcome::Pending if task_id.is_empty() => {
+                        return Err(invalid_input("status has no task_id to poll"));
+                    }
+                    TaskOutcome::Pending => task_id,
                 }
             }
-        } else {
-            return Err(Error::InvalidInput("callback payload must be str or bytes".into()).into());
+            Err(_) => task_id.extract()?,
         };
+        let token = self.token(token)?;
 
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let response = transcribe_callback(&payload, &token).await?;
-            Ok(CallbackResponse::from(response))
+        self.run_limited(py, async move {
+            let poll = poll_status(&task_id, &token, interval);
+            let status = within_timeout(timeout, "status polling", poll).await?;
+            Ok(StatusResponse::from(status))
         })
     }
 
+    #[blocking = transcribe_status_many_blocking]
+    /// With `dedupe`, each distinct id is fetched once and its status repeated
+    /// for every occurrence.
+    #[pyo3(signature = (task_ids, *, token=None, dedupe=false, timeout_seconds=None))]
+    pub fn transcribe_status_many<'py>(
+        &self,
+        py: Python<'py>,
+        task_ids: Vec<String>,
+        token: Option<Bound<'py, PyAny>>,
+        dedupe: bool,
+        timeout_seconds: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let token = self.token(token)?;
+        let limiter = self.limiter.clone();
+
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+
+        // Not `run_limited`: holding a permit here while the requests wait for
+        // theirs would deadlock under a limit of one.
+        self.run(
+            py,
+            within_timeout(timeout, "transcribe_status_many", async move {
+                if !dedupe {
+                    return fetch_statuses(task_ids, token, limiter).await;
+                }
+                let unique = unique_task_ids(task_ids.clone());
+                let fetched: HashMap<String, StatusResponse> = unique
+                    .iter()
+                    .cloned()
+                    .zip(fetch_statuses(unique.clone(), token, limiter).await?)
+                    .collect();
+                Ok(task_ids
+                    .iter()
+                    .map(|task_id| fetched[task_id].clone())
+                    .collect::<Vec<_>>())
+            }),
+        )
+    }
+
+    #[blocking = get_keywords_blocking]
+    /// Only the keywords leave Rust; the utterance details are never
+    /// converted into Python objects.
+    #[pyo3(signature = (task_id, *, token=None, timeout_seconds=None))]
+    pub fn get_keywords<'py>(
+        &self,
+        py: Python<'py>,
+        task_id: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        timeout_seconds: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let task_id: String = task_id.extract()?;
+        let token = self.token(token)?;
+
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+
+        self.run_limited(
+            py,
+            within_timeout(timeout, "get_keywords", async move {
+                let status = transcribe_status(Some(&task_id), None, &token)
+                    .await
+                    .map_err(to_py_err)?;
+                Ok(status.keywords)
+            }),
+        )
+    }
+
+    #[blocking = transcribe_callback_blocking]
+    #[pyo3(signature = (request, token=None, *, timeout_seconds=None))]
+    pub fn transcribe_callback<'py>(
+        &self,
+        py: Python<'py>,
+        request: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        timeout_seconds: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let token = self.token(token)?;
+        let (text, payload): (String, CallbackRequest) =
+            if request.is_instance_of::<PyBytes>() || request.is_instance_of::<PyString>() {
+                let text: String = request.extract()?;
+                match from_json_lenient(&text) {
+                    Ok(value) => (text, value),
+                    Err(err) => {
+                        return Err(invalid_input(format!("invalid callback payload: {err}")));
+                    }
+                }
+            } else {
+                return Err(invalid_input("callback payload must be str or bytes"));
+            };
+        let capture = self.capture.clone();
+
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+
+        self.run_limited(
+            py,
+            within_timeout(timeout, "transcribe_callback", async move {
+                let result = transcribe_callback(&payload, &token)
+                    .await
+                    .map(CallbackResponse::from);
+                if let Some(capture) = &capture {
+                    let request = json!({
+                        "request": text,
+                        "token": redact_token(&token),
+                    });
+                    capture.record("transcribe_callback", request, describe(&result));
+                }
+                result.map_err(to_py_err)
+            }),
+        )
+    }
+
+    #[blocking = transcribe_share_link_blocking]
+    #[pyo3(signature = (task_id, expiration_days=None, token=None, *, timeout_seconds=None))]
     pub fn transcribe_share_link<'py>(
         &self,
         py: Python<'py>,
         task_id: Bound<'py, PyAny>,
         expiration_days: Option<i32>,
-        token: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        timeout_seconds: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let task_id: String = task_id.extract()?;
-        let token: String = token.extract()?;
+        let token = self.token(token)?;
+        let capture = self.capture.clone();
 
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let response = get_share_link(&task_id, expiration_days, &token).await?;
-            Ok(ShareLinkResponse::from(response))
-        })
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+
+        self.run_limited(
+            py,
+            within_timeout(timeout, "transcribe_share_link", async move {
+                let result = get_share_link(&task_id, expiration_days, &token)
+                    .await
+                    .map(ShareLinkResponse::from);
+                if let Some(capture) = &capture {
+                    let request = json!({
+                        "task_id": task_id,
+                        "expiration_days": expiration_days,
+                        "token": redact_token(&token),
+                    });
+                    capture.record("transcribe_share_link", request, describe(&result));
+                }
+                result.map_err(to_py_err)
+            }),
+        )
     }
 
+    #[blocking = transcribe_create_summary_blocking]
+    #[pyo3(signature = (utterances, token=None, *, timeout_seconds=None))]
     pub fn transcribe_create_summary<'py>(
         &self,
         py: Python<'py>,
         utterances: Bound<'py, PyAny>,
-        token: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        timeout_seconds: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let token = self.token(token)?;
+        let utterances: Vec<Utterance> = extract_utterances(utterances)?;
+        let capture = self.capture.clone();
+
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+
+        self.run_limited(
+            py,
+            within_timeout(timeout, "transcribe_create_summary", async move {
+                let count = utterances.len();
+                let result = create_summary(utterances, &token)
+                    .await
+                    .map(SummaryCreateResponse::from);
+                if let Some(capture) = &capture {
+                    let request = json!({
+                        "utterances": count,
+                        "token": redact_token(&token),
+                    });
+                    capture.record("transcribe_create_summary", request, describe(&result));
+                }
+                result.map_err(to_py_err)
+            }),
+        )
+    }
+
+    #[blocking = transcribe_create_summary_chunked_blocking]
+    #[pyo3(signature = (
+        utterances,
+        *,
+        token=None,
+        max_chunk_chars=10_000,
+        combine=false,
+        poll_interval_seconds=2.0,
+        timeout_seconds=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn transcribe_create_summary_chunked<'py>(
+        &self,
+        py: Python<'py>,
+        utterances: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        max_chunk_chars: usize,
+        combine: bool,
+        poll_interval_seconds: f64,
+        timeout_seconds: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let token: String = token.extract()?;
+        let token = self.token(token)?;
         let utterances: Vec<Utterance> = extract_utterances(utterances)?;
+        if max_chunk_chars == 0 {
+            return Err(invalid_input("max_chunk_chars must be positive"));
+        }
+        let interval = parse_interval(poll_interval_seconds, "poll_interval_seconds")?;
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+        let chunks = chunk_by_chars(utterances, max_chunk_chars);
 
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let response = create_summary(utterances, &token).await?;
-            Ok(SummaryCreateResponse::from(response))
+        self.run_limited(py, async move {
+            let summary = create_chunked_summary(chunks, token, combine, interval);
+            within_timeout(timeout, "chunked summary", summary).await
         })
     }
 
+    #[blocking = transcribe_export_blocking]
+    #[pyo3(signature = (
+        task_id,
+        r#type,
+        format,
+        token=None,
+        *,
+        speaker_labels=None,
+        validate=false,
+        output_path=None,
+        retry=None,
+        timeout_seconds=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
     pub fn transcribe_export<'py>(
         &self,
         py: Python<'py>,
         task_id: Bound<'py, PyAny>,
         r#type: Bound<'py, PyAny>,
         format: Bound<'py, PyAny>,
-        token: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        speaker_labels: Option<HashMap<i32, String>>,
+        validate: bool,
+        output_path: Option<String>,
+        retry: Option<PyRef<'py, RetryConfig>>,
+        timeout_seconds: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let task_id: String = task_id.extract()?;
         let r#type: String = r#type.extract()?;
         let format: String = format.extract()?;
-        let token: String = token.extract()?;
+        let token = self.token(token)?;
 
         let export_type = parse_export_type(&r#type)?;
         let export_format = parse_export_format(&format)?;
+        // The name `check_export` expects, e.g. `pdf` for `PDF`.
+        let format_name = normalize_name(&format);
+        let client_side = !matches!(export_format, ExportTarget::Server(_));
+        if client_side && !matches!(export_type, ExportType::Transcript) {
+            return Err(invalid_input(format!(
+                "{format_name} exports are only available for transcripts, not '{}'",
+                r#type
+            )));
+        }
+        let txt = matches!(export_format, ExportTarget::Server(ExportFormat::Txt));
+        if speaker_labels.is_some() && !txt {
+            return Err(invalid_input(format!(
+                "speaker_labels can only be applied to txt exports, not '{format}'"
+            )));
+        }
 
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let data = transcribe_export(&task_id, export_type, export_format, &token).await?;
-            Ok(data.to_vec())
-        })
+        let capture = self.capture.clone();
+        let retry = retry.as_deref().cloned();
+
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+
+        self.run_limited(
+            py,
+            within_timeout(timeout, "transcribe_export", async move {
+                let result = match export_format {
+                    ExportTarget::Server(_) => with_retry(retry.as_ref(), || async {
+                        let export_type = reparse(parse_export_type(&r#type))?;
+                        let ExportTarget::Server(export_format) =
+                            reparse(parse_export_format(&format))?
+                        else {
+                            unreachable!("format was already parsed as a server export");
+                        };
+                        transcribe_export(&task_id, export_type, export_format, &token).await
+                    })
+                    .await
+                    .map(|data| data.to_vec()),
+                    ExportTarget::Srt | ExportTarget::Vtt => with_retry(retry.as_ref(), || {
+                        transcribe_status(Some(&task_id), None, &token)
+                    })
+                    .await
+                    .map(|status| match export_format {
+                        ExportTarget::Vtt => render_vtt(&status.details),
+                        _ => render_srt(&status.details),
+                    })
+                    .map(String::into_bytes),
+                };
+                if let Some(capture) = &capture {
+                    let request = json!({
+                        "task_id": task_id,
+                        "type": r#type,
+                        "format": format,
+                        "token": redact_token(&token),
+                    });
+                    let outcome = result
+                        .as_ref()
+                        .map(|data| format!("<{} bytes>", data.len()))
+                        .map_err(ToString::to_string);
+                    capture.record("transcribe_export", request, outcome);
+                }
+                let data = result.map_err(to_py_err)?;
+                if validate && !client_side {
+                    check_export(&data, &format_name).map_err(invalid_input)?;
+                }
+                let data = match speaker_labels {
+                    Some(labels) => {
+                        let text = String::from_utf8(data).map_err(|err| {
+                            invalid_input(format!("txt export is not valid UTF-8: {err}"))
+                        })?;
+                        relabel_speakers(&text, &labels).into_bytes()
+                    }
+                    None => data,
+                };
+                match output_path {
+                    Some(path) => {
+                        write_atomically(&path, &data).await?;
+                        Python::attach(|py| path.into_py_any(py))
+                    }
+                    // Explicitly `bytes`, never a list of ints.
+                    None => Python::attach(|py| Ok(PyBytes::new(py, &data).into_any().unbind())),
+                }
+            }),
+        )
     }
 
+    #[blocking = transcribe_export_csv_blocking]
+    #[pyo3(signature = (task_id, path, *, token=None, timeout_seconds=None))]
+    pub fn transcribe_export_csv<'py>(
+        &self,
+        py: Python<'py>,
+        task_id: Bound<'py, PyAny>,
+        path: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        timeout_seconds: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let task_id: String = task_id.extract()?;
+        let path: String = path.extract()?;
+        let token = self.token(token)?;
+        let capture = self.capture.clone();
+
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+
+        self.run_limited(
+            py,
+            within_timeout(timeout, "transcribe_export_csv", async move {
+                let result = transcribe_status(Some(&task_id), None, &token).await;
+                if let Some(capture) = &capture {
+                    let request = json!({
+                        "task_id": task_id,
+                        "token": redact_token(&token),
+                    });
+                    let outcome = result
+                        .as_ref()
+                        .map(|status| format!("<{} utterances>", status.details.len()))
+                        .map_err(ToString::to_string);
+                    capture.record("transcribe_export_csv", request, outcome);
+                }
+                let details = result.map_err(to_py_err)?.details;
+                let rows =
+                    tokio::task::spawn_blocking(move || write_utterances_csv(&path, &details))
+                        .await
+                        .map_err(|err| {
+                            DianyaError::new_err(format!("csv export task failed: {err}"))
+                        })??;
+                Ok(rows)
+            }),
+        )
+    }
+
+    #[blocking = translate_text_blocking]
+    #[pyo3(signature = (
+        text, language, token=None, *, glossary=None, retry=None, timeout_seconds=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
     pub fn translate_text<'py>(
         &self,
         py: Python<'py>,
         text: Bound<'py, PyAny>,
         language: Bound<'py, PyAny>,
-        token: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        glossary: Option<HashMap<String, String>>,
+        retry: Option<PyRef<'py, RetryConfig>>,
+        timeout_seconds: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let text: String = text.extract()?;
         let language_str: String = language.extract()?;
-        let language = parse_language(&language_str)?;
-        let token: String = token.extract()?;
+        parse_language(&language_str)?;
+        let token = self.token(token)?;
+        let glossary = Glossary::new(glossary.unwrap_or_default())?;
+        let capture = self.capture.clone();
+        let retry = retry.as_deref().cloned();
 
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let response = translate_text(&text, language, &token).await?;
-            Ok(TextTranslationResponse::from(response))
-        })
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+
+        self.run_limited(
+            py,
+            within_timeout(timeout, "translate_text", async move {
+                let result = with_retry(retry.as_ref(), || async {
+                    let language = reparse(parse_language(&language_str))?;
+                    translate_text(&text, language, &token).await
+                })
+                .await
+                .map(TextTranslationResponse::from)
+                .map(|mut response| {
+                    response.apply_glossary(&glossary);
+                    response
+                });
+                if let Some(capture) = &capture {
+                    let request = json!({
+                        "text": text,
+                        "language": language_str,
+                        "token": redact_token(&token),
+                    });
+                    capture.record("translate_text", request, describe(&result));
+                }
+                result.map_err(to_py_err)
+            }),
+        )
     }
 
+    #[blocking = translate_utterances_blocking]
+    #[pyo3(signature = (
+        utterances, language, token=None, *, glossary=None, retry=None, timeout_seconds=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
     pub fn translate_utterances<'py>(
         &self,
         py: Python<'py>,
         utterances: Bound<'py, PyAny>,
         language: Bound<'py, PyAny>,
-        token: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        glossary: Option<HashMap<String, String>>,
+        retry: Option<PyRef<'py, RetryConfig>>,
+        timeout_seconds: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let utterances: Vec<Utterance> = extract_utterances(utterances)?;
         let language_str: String = language.extract()?;
-        let language = parse_language(&language_str)?;
-        let token: String = token.extract()?;
+        parse_language(&language_str)?;
+        let token = self.token(token)?;
+        let glossary = Glossary::new(glossary.unwrap_or_default())?;
+        let capture = self.capture.clone();
+        let retry = retry.as_deref().cloned();
 
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let response = translate_utterance(utterances, language, &token).await?;
-            Ok(UtteranceTranslationResponse::from(response))
-        })
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+
+        self.run_limited(
+            py,
+            within_timeout(timeout, "translate_utterances", async move {
+                let count = utterances.len();
+                let result = with_retry(retry.as_ref(), || async {
+                    let language = reparse(parse_language(&language_str))?;
+                    let attempt = utterances
+                        .iter()
+                        .map(|utterance| Utterance {
+                            text: utterance.text.clone(),
+                            ..*utterance
+                        })
+                        .collect();
+                    translate_utterance(attempt, language, &token).await
+                })
+                .await
+                .map(UtteranceTranslationResponse::from)
+                .map(|mut response| {
+                    response.apply_glossary(&glossary);
+                    response
+                });
+                if let Some(capture) = &capture {
+                    let request = json!({
+                        "utterances": count,
+                        "language": language_str,
+                        "token": redact_token(&token),
+                    });
+                    capture.record("translate_utterances", request, describe(&result));
+                }
+                result.map_err(to_py_err)
+            }),
+        )
     }
 
+    #[blocking = translate_transcribe_blocking]
+    #[pyo3(signature = (task_id, language, token=None, *, retry=None, timeout_seconds=None))]
     pub fn translate_transcribe<'py>(
         &self,
         py: Python<'py>,
         task_id: Bound<'py, PyAny>,
         language: Bound<'py, PyAny>,
-        token: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        retry: Option<PyRef<'py, RetryConfig>>,
+        timeout_seconds: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let task_id: String = task_id.extract()?;
         let language_str: String = language.extract()?;
-        let language = parse_language(&language_str)?;
-        let token: String = token.extract()?;
+        parse_language(&language_str)?;
+        let token = self.token(token)?;
+        let capture = self.capture.clone();
+        let retry = retry.as_deref().cloned();
 
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let response = translate_transcribe(&task_id, language, &token).await?;
-            Ok(TranscribeTranslationResponse::from(response))
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+
+        self.run_limited(
+            py,
+            within_timeout(timeout, "translate_transcribe", async move {
+                let result = with_retry(retry.as_ref(), || async {
+                    let language = reparse(parse_language(&language_str))?;
+                    translate_transcribe(&task_id, language, &token).await
+                })
+                .await
+                .map(TranscribeTranslationResponse::from);
+                if let Some(capture) = &capture {
+                    let request = json!({
+                        "task_id": task_id,
+                        "language": language_str,
+                        "token": redact_token(&token),
+                    });
+                    capture.record("translate_transcribe", request, describe(&result));
+                }
+                result.map_err(to_py_err)
+            }),
+        )
+    }
+
+    #[blocking = poll_translation_until_done_blocking]
+    #[pyo3(signature = (
+        task_id, language, *, token=None, poll_interval_seconds=2.0, timeout_seconds=None
+    ))]
+    pub fn poll_translation_until_done<'py>(
+        &self,
+        py: Python<'py>,
+        task_id: Bound<'py, PyAny>,
+        language: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        poll_interval_seconds: f64,
+        timeout_seconds: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let task_id: String = task_id.extract()?;
+        let language_str: String = language.extract()?;
+        parse_language(&language_str)?;
+        let token = self.token(token)?;
+        let interval = parse_interval(poll_interval_seconds, "poll_interval_seconds")?;
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+
+        self.run_limited(py, async move {
+            let poll = poll_translation(&task_id, &language_str, &token, interval);
+            within_timeout(timeout, "translation polling", poll).await
         })
     }
+
+    #[blocking = translate_with_summary_blocking]
+    #[pyo3(signature = (
+        task_id, language, *, token=None, poll_interval_seconds=2.0, timeout_seconds=None
+    ))]
+    pub fn translate_with_summary<'py>(
+        &self,
+        py: Python<'py>,
+        task_id: Bound<'py, PyAny>,
+        language: Bound<'py, PyAny>,
+        token: Option<Bound<'py, PyAny>>,
+        poll_interval_seconds: f64,
+        timeout_seconds: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let task_id: String = task_id.extract()?;
+        let language_str: String = language.extract()?;
+        parse_language(&language_str)?;
+        let token = self.token(token)?;
+        let interval = parse_interval(poll_interval_seconds, "poll_interval_seconds")?;
+        let timeout = timeout_seconds
+            .map(|value| parse_seconds(value, "timeout_seconds"))
+            .transpose()?;
+
+        self.run_limited(py, async move {
+            let run = translate_and_summarize(&task_id, &language_str, &token, interval);
+            within_timeout(timeout, "translated summary", run).await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{sync::atomic::AtomicUsize, time::Duration};
+
+    #[tokio::test(start_paused = true)]
+    async fn permits_cap_concurrent_calls() {
+        let limiter = Arc::new(Semaphore::new(3));
+        let active = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let mut calls = JoinSet::new();
+        for _ in 0..10 {
+            let (limiter, active, peak) = (limiter.clone(), active.clone(), peak.clone());
+            calls.spawn(async move {
+                let _permit = acquire_permit(Some(limiter)).await.unwrap();
+                let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                active.fetch_sub(1, Ordering::SeqCst);
+            });
+        }
+        while let Some(call) = calls.join_next().await {
+            call.unwrap();
+        }
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+        assert_eq!(limiter.available_permits(), 3);
+    }
+
+    #[tokio::test]
+    async fn no_limiter_needs_no_permit() {
+        assert!(acquire_permit(None).await.unwrap().is_none());
+    }
 }
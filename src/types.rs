@@ -1,63 +1,630 @@
-use common::Error;
-use pyo3::{Bound, PyAny, PyResult, types::PyAnyMethods};
+use crate::{
+    errors::{http_status, invalid_input},
+    py_types::UtterancePayload,
+};
+use pyo3::{
+    Bound, FromPyObject, PyAny, PyRef, PyResult,
+    buffer::PyBuffer,
+    pyfunction,
+    types::{PyAnyMethods, PyBytes, PyBytesMethods, PyDict, PyDictMethods, PyTypeMethods},
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
 use transcribe::{
     Utterance,
     transcribe::{ExportFormat, ExportType, ModelType},
     translate::Language,
 };
 
+/// Lowercase snake_case form of an enum-like name, so `QualityV2`,
+/// `QUALITY_V2` and `quality-v2` all read as `quality_v2`.
+pub(crate) fn normalize_name(value: &str) -> String {
+    let mut normalized = String::with_capacity(value.len() + 2);
+    let mut previous: Option<char> = None;
+    for ch in value.trim().chars() {
+        match ch {
+            '-' | ' ' => normalized.push('_'),
+            _ => {
+                // A word boundary inside PascalCase, e.g. `yV` in `QualityV2`.
+                if ch.is_ascii_uppercase() && previous.is_some_and(|p| p.is_ascii_lowercase()) {
+                    normalized.push('_');
+                }
+                normalized.push(ch.to_ascii_lowercase());
+            }
+        }
+        previous = Some(ch);
+    }
+    normalized
+}
+
+/// Names of the transcription models. `parse_model` and `supported_models`
+/// are both driven by `MODELS`.
+const MODELS: [&str; 3] = ["speed", "quality", "quality_v2"];
+
+/// SDK variant for a name from `MODELS`.
+fn model_variant(name: &str) -> Option<ModelType> {
+    match name {
+        "speed" => Some(ModelType::Speed),
+        "quality" => Some(ModelType::Quality),
+        "quality_v2" => Some(ModelType::QualityV2),
+        _ => None,
+    }
+}
+
 pub fn parse_model(value: &str) -> PyResult<ModelType> {
-    match value.to_ascii_lowercase().as_str() {
-        "speed" => Ok(ModelType::Speed),
-        "quality" => Ok(ModelType::Quality),
-        "quality_v2" => Ok(ModelType::QualityV2),
-        invalid => Err(Error::InvalidInput(format!(
-            "unsupported model '{invalid}' (expected 'speed' 'quality' or 'quality_v2')"
-        ))
-        .into()),
+    let normalized = normalize_name(value);
+    MODELS
+        .iter()
+        .find(|name| **name == normalized)
+        .and_then(|name| model_variant(name))
+        .ok_or_else(|| {
+            let names: Vec<String> = MODELS.iter().map(|name| format!("'{name}'")).collect();
+            invalid_input(format!(
+                "unsupported model '{value}' (expected one of {})",
+                names.join(", ")
+            ))
+        })
+}
+
+/// Inverse of `parse_model`. The exhaustive match makes a new SDK variant a
+/// compile error here until it is added to `MODELS` as well.
+pub fn model_to_str(model: ModelType) -> &'static str {
+    match model {
+        ModelType::Speed => "speed",
+        ModelType::Quality => "quality",
+        ModelType::QualityV2 => "quality_v2",
     }
 }
 
+/// Every model name `parse_model` accepts.
+#[pyfunction]
+pub fn supported_models() -> Vec<&'static str> {
+    MODELS.to_vec()
+}
+
 pub fn parse_export_type(value: &str) -> PyResult<ExportType> {
-    match value.to_ascii_lowercase().as_str() {
+    match normalize_name(value).as_str() {
         "transcript" => Ok(ExportType::Transcript),
         "overview" => Ok(ExportType::Overview),
         "summary" => Ok(ExportType::Summary),
-        invalid => Err(Error::InvalidInput(format!("unsupported export type '{invalid}'")).into()),
+        _ => Err(invalid_input(format!("unsupported export type '{value}'"))),
     }
 }
 
-pub fn parse_export_format(value: &str) -> PyResult<ExportFormat> {
-    match value.to_ascii_lowercase().as_str() {
-        "pdf" => Ok(ExportFormat::Pdf),
-        "txt" => Ok(ExportFormat::Txt),
-        "docx" => Ok(ExportFormat::Docx),
-        invalid => {
-            Err(Error::InvalidInput(format!("unsupported export format '{invalid}'")).into())
-        }
+/// Where an export is produced: by the server, or client-side for formats the
+/// export endpoint lacks.
+#[derive(Debug)]
+pub enum ExportTarget {
+    Server(ExportFormat),
+    /// Rendered from the transcript details, like `Vtt`.
+    Srt,
+    Vtt,
+}
+
+pub fn parse_export_format(value: &str) -> PyResult<ExportTarget> {
+    match normalize_name(value).as_str() {
+        "pdf" => Ok(ExportTarget::Server(ExportFormat::Pdf)),
+        "txt" => Ok(ExportTarget::Server(ExportFormat::Txt)),
+        "docx" => Ok(ExportTarget::Server(ExportFormat::Docx)),
+        "srt" => Ok(ExportTarget::Srt),
+        "vtt" => Ok(ExportTarget::Vtt),
+        _ => Err(invalid_input(format!(
+            "unsupported export format '{value}'"
+        ))),
+    }
+}
+
+/// One supported translation language. `parse_language`, `supported_languages`
+/// and `language_from_native_name` are all driven by `LANGUAGES`.
+struct LanguageEntry {
+    code: &'static str,
+    aliases: &'static [&'static str],
+    display_name: &'static str,
+    /// Lowercase native and English names accepted by `language_from_native_name`.
+    names: &'static [&'static str],
+}
+
+const LANGUAGES: [LanguageEntry; 6] = [
+    LanguageEntry {
+        code: "zh",
+        aliases: &["zh-cn"],
+        display_name: "Chinese (Simplified)",
+        names: &[
+            "中文",
+            "简体中文",
+            "汉语",
+            "普通话",
+            "chinese",
+            "simplified chinese",
+            "chinese (simplified)",
+            "mandarin",
+        ],
+    },
+    LanguageEntry {
+        code: "en",
+        aliases: &["en-us"],
+        display_name: "English (US)",
+        names: &["english", "english (us)", "american english"],
+    },
+    LanguageEntry {
+        code: "ja",
+        aliases: &["jp"],
+        display_name: "Japanese",
+        names: &["日本語", "日本语", "japanese"],
+    },
+    LanguageEntry {
+        code: "ko",
+        aliases: &["kr"],
+        display_name: "Korean",
+        names: &["한국어", "韩语", "korean"],
+    },
+    LanguageEntry {
+        code: "fr",
+        aliases: &[],
+        display_name: "French",
+        names: &["français", "francais", "french"],
+    },
+    LanguageEntry {
+        code: "de",
+        aliases: &[],
+        display_name: "German",
+        names: &["deutsch", "german"],
+    },
+];
+
+/// SDK variant for a canonical code from `LANGUAGES`.
+fn language_variant(code: &str) -> Option<Language> {
+    match code {
+        "zh" => Some(Language::ChineseSimplified),
+        "en" => Some(Language::EnglishUS),
+        "ja" => Some(Language::Japanese),
+        "ko" => Some(Language::Korean),
+        "fr" => Some(Language::French),
+        "de" => Some(Language::German),
+        _ => None,
     }
 }
 
 pub fn parse_language(value: &str) -> PyResult<Language> {
-    match value.to_ascii_lowercase().as_str() {
-        "zh" | "zh-cn" => Ok(Language::ChineseSimplified),
-        "en" | "en-us" => Ok(Language::EnglishUS),
-        "ja" => Ok(Language::Japanese),
-        "ko" | "kr" | "jp" => Ok(Language::Korean),
-        "fr" => Ok(Language::French),
-        "de" => Ok(Language::German),
-        invalid => {
-            Err(Error::InvalidInput(format!("unsupported language code '{invalid}'")).into())
+    let value = value.to_ascii_lowercase();
+    LANGUAGES
+        .iter()
+        .find(|entry| entry.code == value || entry.aliases.contains(&value.as_str()))
+        .and_then(|entry| language_variant(entry.code))
+        .ok_or_else(|| {
+            let codes: Vec<String> = LANGUAGES
+                .iter()
+                .map(|entry| format!("'{}'", entry.code))
+                .collect();
+            invalid_input(format!(
+                "unsupported language code '{value}' (expected one of {})",
+                codes.join(", ")
+            ))
+        })
+}
+
+/// `(code, display_name)` for every language `parse_language` accepts.
+#[pyfunction]
+pub fn supported_languages() -> Vec<(&'static str, &'static str)> {
+    LANGUAGES
+        .iter()
+        .map(|entry| (entry.code, entry.display_name))
+        .collect()
+}
+
+#[pyfunction]
+pub fn language_from_native_name(name: &str) -> PyResult<&'static str> {
+    let normalized = name.trim().to_lowercase();
+    LANGUAGES
+        .iter()
+        .find(|entry| entry.names.contains(&normalized.as_str()))
+        .map(|entry| entry.code)
+        .ok_or_else(|| invalid_input(format!("unsupported language name '{name}'")))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskOutcome {
+    Pending,
+    Done,
+    Failed,
+}
+
+pub fn task_outcome(status: &str) -> TaskOutcome {
+    match status.to_ascii_lowercase().as_str() {
+        "done" | "success" | "succeeded" | "completed" | "finished" => TaskOutcome::Done,
+        "failed" | "error" | "cancelled" | "canceled" | "expired" => TaskOutcome::Failed,
+        other if other.ends_with("_failed") || other.ends_with("_error") => TaskOutcome::Failed,
+        _ => TaskOutcome::Pending,
+    }
+}
+
+/// The SDK reports a missing task as an ordinary error, so "does not exist"
+/// is recognised from the error message: an HTTP 404 or a not-found phrase.
+pub fn is_not_found(message: &str) -> bool {
+    if http_status(message) == Some(404) {
+        return true;
+    }
+    let message = message.to_ascii_lowercase();
+    message.contains("not found") || message.contains("not_found") || message.contains("not exist")
+}
+
+/// Recognises an upload rejected because the requested model cannot be used
+/// right now, as opposed to a bad file or token.
+pub fn is_model_unavailable(message: &str) -> bool {
+    let message = message.to_ascii_lowercase();
+    message.contains("model")
+        && [
+            "unavailable",
+            "not available",
+            "not supported",
+            "unsupported",
+            "not enabled",
+        ]
+        .iter()
+        .any(|reason| message.contains(reason))
+}
+
+/// Distinct task ids in order of first occurrence.
+#[pyfunction]
+pub fn unique_task_ids(task_ids: Vec<String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    task_ids
+        .into_iter()
+        .filter(|task_id| seen.insert(task_id.clone()))
+        .collect()
+}
+
+pub fn parse_seconds(value: f64, name: &str) -> PyResult<Duration> {
+    if !value.is_finite() || value < 0.0 {
+        return Err(invalid_input(format!(
+            "{name} must be a non-negative number of seconds, got {value}"
+        )));
+    }
+    Ok(Duration::from_secs_f64(value))
+}
+
+/// `parse_seconds` for a delay that is waited on repeatedly, where zero would
+/// turn the wait into a busy loop.
+pub fn parse_interval(value: f64, name: &str) -> PyResult<Duration> {
+    let interval = parse_seconds(value, name)?;
+    if interval.is_zero() {
+        return Err(invalid_input(format!(
+            "{name} must be a positive number of seconds, got {value}"
+        )));
+    }
+    Ok(interval)
+}
+
+/// Numeric fields that some server versions send as strings.
+const LENIENT_NUMERIC_FIELDS: [&str; 4] = ["speaker", "start_time", "end_time", "max_time"];
+
+static LENIENT_NUMBERS: AtomicBool = AtomicBool::new(true);
+
+#[pyfunction]
+pub fn set_lenient_numbers(enabled: bool) {
+    LENIENT_NUMBERS.store(enabled, Ordering::Relaxed);
+}
+
+pub fn lenient_numbers() -> bool {
+    LENIENT_NUMBERS.load(Ordering::Relaxed)
+}
+
+fn coerce_numeric_strings(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                if let Value::String(text) = field
+                    && LENIENT_NUMERIC_FIELDS.contains(&key.as_str())
+                {
+                    let text = text.trim();
+                    if let Ok(integer) = text.parse::<i64>() {
+                        *field = Value::from(integer);
+                    } else if let Ok(float) = text.parse::<f64>() {
+                        *field = Value::from(float);
+                    }
+                } else {
+                    coerce_numeric_strings(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(coerce_numeric_strings),
+        _ => {}
+    }
+}
+
+/// Like `serde_json::from_str`, but accepts string-encoded numbers for the
+/// fields in `LENIENT_NUMERIC_FIELDS` unless disabled via `set_lenient_numbers`.
+pub fn from_json_lenient<T: DeserializeOwned>(text: &str) -> serde_json::Result<T> {
+    if !lenient_numbers() {
+        return serde_json::from_str(text);
+    }
+    from_value_lenient(serde_json::from_str(text)?)
+}
+
+pub fn from_value_lenient<T: DeserializeOwned>(mut value: Value) -> serde_json::Result<T> {
+    if lenient_numbers() {
+        coerce_numeric_strings(&mut value);
+    }
+    serde_json::from_value(value)
+}
+
+/// Term overrides applied to translated text, since the translation API has
+/// no glossary parameter. Longer terms are replaced first so that a term
+/// containing a shorter one wins.
+#[derive(Debug, Clone, Default)]
+pub struct Glossary {
+    terms: Vec<(String, String)>,
+}
+
+impl Glossary {
+    pub fn new(terms: HashMap<String, String>) -> PyResult<Self> {
+        if terms.keys().any(|term| term.is_empty()) {
+            return Err(invalid_input("glossary terms must not be empty"));
         }
+        let mut terms: Vec<(String, String)> = terms.into_iter().collect();
+        terms.sort_by(|(a, _), (b, _)| b.chars().count().cmp(&a.chars().count()).then(a.cmp(b)));
+        Ok(Self { terms })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Replaces in a single left-to-right pass so replacement text is never
+    /// matched again by a later term.
+    pub fn apply(&self, text: &str) -> String {
+        if self.terms.is_empty() {
+            return text.to_string();
+        }
+        let mut output = String::with_capacity(text.len());
+        let mut rest = text;
+        'outer: while !rest.is_empty() {
+            for (term, replacement) in &self.terms {
+                if let Some(tail) = rest.strip_prefix(term.as_str()) {
+                    output.push_str(replacement);
+                    rest = tail;
+                    continue 'outer;
+                }
+            }
+            let mut chars = rest.chars();
+            if let Some(ch) = chars.next() {
+                output.push(ch);
+            }
+            rest = chars.as_str();
+        }
+        output
     }
 }
 
+fn invalid_field(index: usize, field: &str, problem: &str) -> pyo3::PyErr {
+    invalid_input(format!(
+        "invalid utterance at index {index}: '{field}' {problem}"
+    ))
+}
+
+fn utterance_field<'py>(
+    item: &Bound<'py, PyDict>,
+    index: usize,
+    field: &str,
+) -> PyResult<Bound<'py, PyAny>> {
+    item.get_item(field)?
+        .ok_or_else(|| invalid_field(index, field, "is missing"))
+}
+
+/// Numeric field, accepting numeric strings while lenient numbers are enabled.
+fn numeric_field<T: FromStr>(item: &Bound<'_, PyDict>, index: usize, field: &str) -> PyResult<T>
+where
+    for<'a, 'py> T: FromPyObject<'a, 'py>,
+{
+    let value = utterance_field(item, index, field)?;
+    if let Ok(number) = value.extract::<T>() {
+        return Ok(number);
+    }
+    if lenient_numbers()
+        && let Ok(text) = value.extract::<String>()
+        && let Ok(number) = text.trim().parse::<T>()
+    {
+        return Ok(number);
+    }
+    let type_name = value.get_type().name()?;
+    Err(invalid_field(
+        index,
+        field,
+        &format!("has invalid value of type {type_name}"),
+    ))
+}
+
+/// Copies a bytes-like object into an owned buffer exactly once. `bytes` is
+/// read directly; anything else goes through the buffer protocol, which also
+/// covers `bytearray`, `memoryview` and arrays.
+pub fn extract_bytes(data: &Bound<'_, PyAny>) -> PyResult<Vec<u8>> {
+    if let Ok(bytes) = data.cast::<PyBytes>() {
+        return Ok(bytes.as_bytes().to_vec());
+    }
+    let buffer = PyBuffer::<u8>::get(data)?;
+    buffer.to_vec(data.py())
+}
+
 pub fn extract_utterances(value: Bound<'_, PyAny>) -> PyResult<Vec<Utterance>> {
-    let py = value.py();
-    let dumped = py
-        .import("json")?
-        .call_method1("dumps", (value,))?
-        .extract::<String>()?;
-    serde_json::from_str(&dumped)
-        .map_err(|err| Error::InvalidInput(format!("invalid utterances payload: {err}")).into())
+    value
+        .try_iter()?
+        .enumerate()
+        .map(|(index, item)| -> PyResult<Utterance> {
+            let item = item?;
+            if let Ok(payload) = item.extract::<PyRef<'_, UtterancePayload>>() {
+                return Ok(Utterance::from(&*payload));
+            }
+            let Ok(item) = item.cast::<PyDict>() else {
+                let type_name = item.get_type().name()?;
+                return Err(invalid_input(format!(
+                    "invalid utterance at index {index}: expected a dict or UtterancePayload, \
+                     got {type_name}"
+                )));
+            };
+            let text = utterance_field(item, index, "text")?
+                .extract::<String>()
+                .map_err(|_| invalid_field(index, "text", "must be a string"))?;
+            Ok(Utterance {
+                start_time: numeric_field(item, index, "start_time")?,
+                end_time: numeric_field(item, index, "end_time")?,
+                text,
+                speaker: numeric_field(item, index, "speaker")?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::Python;
+
+    #[test]
+    fn normalize_name_accepts_pascal_case() {
+        assert_eq!(normalize_name("QualityV2"), "quality_v2");
+        assert_eq!(normalize_name("Transcript"), "transcript");
+        assert_eq!(normalize_name("PDF"), "pdf");
+    }
+
+    #[test]
+    fn normalize_name_accepts_hyphens_and_spaces() {
+        assert_eq!(normalize_name("quality-v2"), "quality_v2");
+        assert_eq!(normalize_name(" Quality V2 "), "quality_v2");
+        assert_eq!(normalize_name("quality_v2"), "quality_v2");
+    }
+
+    #[test]
+    fn parse_model_uses_normalized_names() {
+        // The rejection is built as a Python exception.
+        Python::initialize();
+        assert!(matches!(parse_model("QualityV2"), Ok(ModelType::QualityV2)));
+        assert!(matches!(
+            parse_model("quality-v2"),
+            Ok(ModelType::QualityV2)
+        ));
+        assert!(parse_model("quality-v3").is_err());
+    }
+
+    #[test]
+    fn parse_language_accepts_aliases() {
+        assert!(matches!(parse_language("ja"), Ok(Language::Japanese)));
+        assert!(matches!(parse_language("jp"), Ok(Language::Japanese)));
+        assert!(matches!(parse_language("JP"), Ok(Language::Japanese)));
+        assert!(matches!(parse_language("ko"), Ok(Language::Korean)));
+        assert!(matches!(parse_language("kr"), Ok(Language::Korean)));
+        assert!(matches!(
+            parse_language("zh-CN"),
+            Ok(Language::ChineseSimplified)
+        ));
+        assert!(matches!(parse_language("en-us"), Ok(Language::EnglishUS)));
+    }
+
+    #[test]
+    fn parse_language_rejects_unknown_codes() {
+        Python::initialize();
+        assert!(parse_language("xx").is_err());
+        assert!(parse_language("zh-tw").is_err());
+    }
+
+    #[test]
+    fn language_from_native_name_matches_native_and_english_names() {
+        assert_eq!(language_from_native_name("日本語").unwrap(), "ja");
+        assert_eq!(language_from_native_name(" Français ").unwrap(), "fr");
+        assert_eq!(language_from_native_name("한국어").unwrap(), "ko");
+        assert_eq!(language_from_native_name("German").unwrap(), "de");
+        assert_eq!(language_from_native_name("普通话").unwrap(), "zh");
+        Python::initialize();
+        assert!(language_from_native_name("Klingon").is_err());
+        // Codes go through `parse_language`, not the name lookup.
+        assert!(language_from_native_name("ja").is_err());
+    }
+
+    #[test]
+    fn every_language_has_a_variant() {
+        for entry in &LANGUAGES {
+            assert!(language_variant(entry.code).is_some(), "{}", entry.code);
+        }
+    }
+
+    #[test]
+    fn parse_interval_rejects_zero() {
+        Python::initialize();
+        assert_eq!(
+            parse_interval(0.5, "poll").unwrap(),
+            Duration::from_millis(500)
+        );
+        assert!(parse_interval(0.0, "poll").is_err());
+        assert!(parse_interval(1e-12, "poll").is_err());
+        assert!(parse_interval(-1.0, "poll").is_err());
+    }
+
+    #[test]
+    fn parse_export_format_uses_normalized_names() {
+        assert!(matches!(
+            parse_export_format("PDF"),
+            Ok(ExportTarget::Server(ExportFormat::Pdf))
+        ));
+        assert!(matches!(parse_export_format("Vtt"), Ok(ExportTarget::Vtt)));
+        assert!(matches!(
+            parse_export_type("Transcript"),
+            Ok(ExportType::Transcript)
+        ));
+    }
+
+    #[test]
+    fn task_outcome_recognises_terminal_statuses() {
+        assert_eq!(task_outcome("done"), TaskOutcome::Done);
+        assert_eq!(task_outcome("Completed"), TaskOutcome::Done);
+        assert_eq!(task_outcome("CANCELLED"), TaskOutcome::Failed);
+        assert_eq!(task_outcome("translate_failed"), TaskOutcome::Failed);
+        assert_eq!(task_outcome("upload_error"), TaskOutcome::Failed);
+        assert_eq!(task_outcome("running"), TaskOutcome::Pending);
+        assert_eq!(task_outcome(""), TaskOutcome::Pending);
+    }
+
+    fn glossary(terms: &[(&str, &str)]) -> Glossary {
+        let terms = terms
+            .iter()
+            .map(|(term, replacement)| (term.to_string(), replacement.to_string()))
+            .collect();
+        Glossary::new(terms).unwrap()
+    }
+
+    #[test]
+    fn glossary_prefers_longer_terms() {
+        let glossary = glossary(&[("AI", "人工智能"), ("AI Lab", "AI 实验室")]);
+        assert_eq!(glossary.apply("AI Lab uses AI"), "AI 实验室 uses 人工智能");
+    }
+
+    #[test]
+    fn glossary_does_not_rematch_replacements() {
+        let glossary = glossary(&[("a", "b"), ("b", "c")]);
+        assert_eq!(glossary.apply("ab"), "bc");
+        assert_eq!(glossary.apply("点 a 点"), "点 b 点");
+    }
+
+    #[test]
+    fn glossary_rejects_empty_terms() {
+        Python::initialize();
+        assert!(Glossary::new(HashMap::from([(String::new(), "x".to_string())])).is_err());
+        assert!(glossary(&[]).is_empty());
+    }
+
+    #[test]
+    fn is_not_found_needs_a_status_or_phrase() {
+        assert!(is_not_found("HTTP 404"));
+        assert!(is_not_found("request failed with status code 404"));
+        assert!(is_not_found("Task not found"));
+        assert!(is_not_found("task_not_found"));
+        assert!(is_not_found("task does not exist"));
+        assert!(!is_not_found("processed 404 items"));
+        assert!(!is_not_found("uploaded 4048 bytes"));
+        assert!(!is_not_found("task-404-a failed"));
+    }
 }
@@ -0,0 +1,391 @@
+use crate::{
+    errors::invalid_input,
+    py_types::{CoverageReport, UploadResult, UtterancePayload},
+    types::{extract_utterances, parse_seconds},
+};
+use pyo3::prelude::*;
+use transcribe::Utterance;
+
+/// Splits utterances into consecutive chunks whose combined text stays within
+/// `max_chars` characters. An utterance longer than the limit gets a chunk of
+/// its own rather than being cut mid-sentence.
+pub fn chunk_by_chars(utterances: Vec<Utterance>, max_chars: usize) -> Vec<Vec<Utterance>> {
+    let mut chunks = Vec::new();
+    let mut current = Vec::new();
+    let mut current_chars = 0;
+
+    for utterance in utterances {
+        let chars = utterance.text.chars().count();
+        if !current.is_empty() && current_chars + chars > max_chars {
+            chunks.push(std::mem::take(&mut current));
+            current_chars = 0;
+        }
+        current_chars += chars;
+        current.push(utterance);
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+/// Time span covered by a list of utterances, or `None` when it is empty.
+pub fn time_span(utterances: &[Utterance]) -> Option<(f64, f64)> {
+    let start = utterances.iter().map(|u| u.start_time).reduce(f64::min)?;
+    let end = utterances.iter().map(|u| u.end_time).reduce(f64::max)?;
+    Some((start, end))
+}
+
+/// Timing problems in a transcript, one message per problem. Overlaps between
+/// consecutive utterances up to `max_overlap` are tolerated as crosstalk;
+/// `None` flags every overlap.
+pub fn timing_issues(utterances: &[Utterance], max_overlap: Option<f64>) -> Vec<String> {
+    let mut issues = Vec::new();
+    for (index, utterance) in utterances.iter().enumerate() {
+        if utterance.start_time < 0.0 {
+            issues.push(format!("utterance {index} starts before zero"));
+        }
+        if utterance.end_time < utterance.start_time {
+            issues.push(format!("utterance {index} ends before it starts"));
+        }
+    }
+
+    for (index, pair) in utterances.windows(2).enumerate() {
+        let (previous, current) = (&pair[0], &pair[1]);
+        if current.start_time < previous.start_time {
+            issues.push(format!(
+                "utterance {} starts before utterance {index}",
+                index + 1
+            ));
+            continue;
+        }
+        let overlap = previous.end_time - current.start_time;
+        let tolerated = max_overlap.is_some_and(|limit| overlap <= limit);
+        if overlap > 0.0 && !tolerated {
+            issues.push(format!(
+                "utterances {index} and {} overlap by {overlap:.3}s",
+                index + 1
+            ));
+        }
+    }
+    issues
+}
+
+#[pyfunction]
+#[pyo3(signature = (utterances, *, allow_overlap=true, max_overlap_seconds=0.5))]
+pub fn validate_utterances(
+    utterances: Bound<'_, PyAny>,
+    allow_overlap: bool,
+    max_overlap_seconds: f64,
+) -> PyResult<Vec<String>> {
+    let utterances = extract_utterances(utterances)?;
+    let max_overlap = parse_seconds(max_overlap_seconds, "max_overlap_seconds")?;
+    let max_overlap = allow_overlap.then_some(max_overlap.as_secs_f64());
+    Ok(timing_issues(&utterances, max_overlap))
+}
+
+/// Truncated transcripts stop early, so coverage is measured up to the end of
+/// the last utterance rather than by summing speech time, which would also
+/// penalize silence.
+#[pyfunction]
+#[pyo3(signature = (utterances, audio_duration, *, threshold=0.9))]
+pub fn transcript_coverage(
+    utterances: Bound<'_, PyAny>,
+    audio_duration: f64,
+    threshold: f64,
+) -> PyResult<CoverageReport> {
+    let utterances = extract_utterances(utterances)?;
+    let audio_seconds = parse_seconds(audio_duration, "audio_duration")?.as_secs_f64();
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err(invalid_input("threshold must be between 0 and 1"));
+    }
+
+    let covered_seconds = time_span(&utterances)
+        .map(|(_, end)| end.clamp(0.0, audio_seconds))
+        .unwrap_or_default();
+    let fraction = if audio_seconds > 0.0 {
+        covered_seconds / audio_seconds
+    } else {
+        1.0
+    };
+    Ok(CoverageReport {
+        covered_seconds,
+        audio_seconds,
+        fraction,
+        likely_truncated: fraction < threshold,
+    })
+}
+
+/// Lays the clips end to end, each starting `gap` after the previous one ends.
+pub fn sequential_utterances(
+    clips: impl IntoIterator<Item = (String, f64)>,
+    gap: f64,
+    speaker: i32,
+) -> Vec<Utterance> {
+    let mut offset = 0.0;
+    clips
+        .into_iter()
+        .map(|(text, duration)| {
+            let utterance = Utterance {
+                start_time: offset,
+                end_time: offset + duration,
+                text,
+                speaker,
+            };
+            offset += duration + gap;
+            utterance
+        })
+        .collect()
+}
+
+#[pyfunction]
+#[pyo3(signature = (results, durations, *, gap_seconds=0.0, speaker=0))]
+pub fn merge_one_sentence_results(
+    results: Vec<Bound<'_, PyAny>>,
+    durations: Vec<f64>,
+    gap_seconds: f64,
+    speaker: i32,
+) -> PyResult<Vec<UtterancePayload>> {
+    if results.len() != durations.len() {
+        return Err(invalid_input(format!(
+            "got {} results but {} durations",
+            results.len(),
+            durations.len()
+        )));
+    }
+    let gap = parse_seconds(gap_seconds, "gap_seconds")?.as_secs_f64();
+
+    let mut clips = Vec::with_capacity(results.len());
+    for (index, (result, duration)) in results.iter().zip(durations).enumerate() {
+        let text = match result.extract::<PyRef<'_, UploadResult>>() {
+            Ok(upload) => upload
+                .one_sentence_text()
+                .map(str::to_string)
+                .ok_or_else(|| {
+                    invalid_input(format!("result {index} is not a one-sentence upload"))
+                })?,
+            Err(_) => result.extract::<String>()?,
+        };
+        let duration = parse_seconds(duration, "durations")?.as_secs_f64();
+        clips.push((text, duration));
+    }
+    Ok(sequential_utterances(clips, gap, speaker)
+        .into_iter()
+        .map(UtterancePayload::from)
+        .collect())
+}
+
+/// Terminators that end a sentence wherever they appear; CJK text puts no
+/// space after them.
+const CJK_TERMINATORS: [char; 5] = ['。', '！', '？', '；', '…'];
+/// Terminators that only end a sentence before whitespace, a closing mark or
+/// the end of the text, so `3.14` and `e.g.x` stay whole.
+const ASCII_TERMINATORS: [char; 4] = ['.', '!', '?', ';'];
+/// Closing quotes and brackets kept with the sentence they end.
+const CLOSERS: [char; 8] = ['"', '\'', '”', '’', ')', '）', '」', '』'];
+
+fn is_terminator(ch: char) -> bool {
+    CJK_TERMINATORS.contains(&ch) || ASCII_TERMINATORS.contains(&ch)
+}
+
+pub fn split_text_sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, ch)) = chars.next() {
+        let boundary = CJK_TERMINATORS.contains(&ch)
+            || (ASCII_TERMINATORS.contains(&ch)
+                && chars
+                    .peek()
+                    .is_none_or(|(_, next)| next.is_whitespace() || CLOSERS.contains(next)));
+        if !boundary {
+            continue;
+        }
+        let mut end = index + ch.len_utf8();
+        while let Some(&(next_index, next)) = chars.peek()
+            && (is_terminator(next) || CLOSERS.contains(&next))
+        {
+            end = next_index + next.len_utf8();
+            chars.next();
+        }
+        let sentence = text[start..end].trim();
+        if !sentence.is_empty() {
+            sentences.push(sentence);
+        }
+        start = end;
+    }
+    let rest = text[start..].trim();
+    if !rest.is_empty() {
+        sentences.push(rest);
+    }
+    sentences
+}
+
+/// Splits each utterance into its sentences, sharing its time span out in
+/// proportion to each sentence's non-whitespace characters.
+pub fn sentence_utterances(utterances: &[Utterance]) -> Vec<Utterance> {
+    let mut split = Vec::with_capacity(utterances.len());
+    for utterance in utterances {
+        let sentences = split_text_sentences(&utterance.text);
+        if sentences.len() <= 1 {
+            split.push(Utterance {
+                text: utterance.text.clone(),
+                ..*utterance
+            });
+            continue;
+        }
+
+        let weight = |text: &str| text.chars().filter(|ch| !ch.is_whitespace()).count();
+        let total: usize = sentences.iter().map(|sentence| weight(sentence)).sum();
+        let span = utterance.end_time - utterance.start_time;
+        let mut consumed = 0;
+        for (index, sentence) in sentences.iter().enumerate() {
+            let start_time = utterance.start_time + span * consumed as f64 / total as f64;
+            consumed += weight(sentence);
+            // The last sentence ends exactly where the utterance did.
+            let end_time = if index + 1 == sentences.len() {
+                utterance.end_time
+            } else {
+                utterance.start_time + span * consumed as f64 / total as f64
+            };
+            split.push(Utterance {
+                start_time,
+                end_time,
+                text: sentence.to_string(),
+                speaker: utterance.speaker,
+            });
+        }
+    }
+    split
+}
+
+#[pyfunction]
+pub fn split_sentences(utterances: Bound<'_, PyAny>) -> PyResult<Vec<UtterancePayload>> {
+    let utterances = extract_utterances(utterances)?;
+    Ok(sentence_utterances(&utterances)
+        .into_iter()
+        .map(UtterancePayload::from)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_text_sentences_handles_cjk_and_ascii() {
+        assert_eq!(
+            split_text_sentences("你好。今天天气不错！"),
+            ["你好。", "今天天气不错！"]
+        );
+        assert_eq!(
+            split_text_sentences("Pi is 3.14. Right?"),
+            ["Pi is 3.14.", "Right?"]
+        );
+        assert_eq!(split_text_sentences("“好。”他说"), ["“好。”", "他说"]);
+        assert!(split_text_sentences("  ").is_empty());
+    }
+
+    #[test]
+    fn sentence_utterances_share_time_by_length() {
+        let utterance = Utterance {
+            start_time: 0.0,
+            end_time: 8.0,
+            text: "ab。cdef。".to_string(),
+            speaker: 2,
+        };
+        let split = sentence_utterances(&[utterance]);
+        let spans: Vec<_> = split
+            .iter()
+            .map(|sentence| (sentence.start_time, sentence.end_time))
+            .collect();
+        assert_eq!(spans, [(0.0, 3.0), (3.0, 8.0)]);
+        assert_eq!(split[1].text, "cdef。");
+        assert!(split.iter().all(|sentence| sentence.speaker == 2));
+    }
+
+    #[test]
+    fn timing_issues_tolerates_overlap_up_to_the_limit() {
+        let at = |start_time, end_time| Utterance {
+            start_time,
+            end_time,
+            text: String::new(),
+            speaker: 0,
+        };
+        let utterances = [at(0.0, 2.0), at(1.7, 3.0), at(2.0, 4.0)];
+        assert!(timing_issues(&utterances, Some(1.0)).is_empty());
+        assert_eq!(
+            timing_issues(&utterances, Some(0.5)),
+            ["utterances 1 and 2 overlap by 1.000s"]
+        );
+        assert_eq!(
+            timing_issues(&utterances, None),
+            [
+                "utterances 0 and 1 overlap by 0.300s",
+                "utterances 1 and 2 overlap by 1.000s",
+            ]
+        );
+        assert_eq!(
+            timing_issues(&[at(-1.0, 1.0), at(0.5, 0.2)], Some(1.0)),
+            [
+                "utterance 0 starts before zero",
+                "utterance 1 ends before it starts",
+            ]
+        );
+    }
+
+    #[test]
+    fn sequential_utterances_accumulate_durations_and_gaps() {
+        let clips = [
+            ("一".to_string(), 1.5),
+            ("二".to_string(), 2.0),
+            ("三".to_string(), 0.5),
+        ];
+        let merged = sequential_utterances(clips, 0.25, 4);
+        let spans: Vec<_> = merged
+            .iter()
+            .map(|utterance| (utterance.start_time, utterance.end_time))
+            .collect();
+        assert_eq!(spans, [(0.0, 1.5), (1.75, 3.75), (4.0, 4.5)]);
+        assert_eq!(merged[2].text, "三");
+        assert!(merged.iter().all(|utterance| utterance.speaker == 4));
+    }
+
+    #[test]
+    fn chunk_by_chars_bounds_each_chunk() {
+        let said = |text: &str| Utterance {
+            start_time: 0.0,
+            end_time: 1.0,
+            text: text.to_string(),
+            speaker: 0,
+        };
+        let utterances = vec![
+            said("一二"),
+            said("三四"),
+            said("五"),
+            said("六七八九十"),
+            said("x"),
+        ];
+        let chunks = chunk_by_chars(utterances, 4);
+        let texts: Vec<Vec<&str>> = chunks
+            .iter()
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .map(|utterance| utterance.text.as_str())
+                    .collect()
+            })
+            .collect();
+        // The five-character utterance exceeds the limit and stands alone.
+        assert_eq!(
+            texts,
+            [
+                vec!["一二", "三四"],
+                vec!["五"],
+                vec!["六七八九十"],
+                vec!["x"]
+            ]
+        );
+        assert!(chunk_by_chars(Vec::new(), 4).is_empty());
+    }
+}
@@ -0,0 +1,74 @@
+import asyncio
+import inspect
+
+import pytest
+
+from dianyaapi import InvalidInputError, StatusResponse, TranscribeApi
+
+DONE = {
+    "status": "done",
+    "task_id": "task-1",
+    "details": [],
+    "keywords": [],
+    "callback_history": [],
+}
+
+
+def test_blocking_from_sync_code():
+    status = StatusResponse.from_dict(DONE)
+    assert TranscribeApi().transcribe_wait_until_done_blocking(status).status == "done"
+
+
+@pytest.mark.asyncio
+async def test_blocking_from_a_coroutine():
+    status = StatusResponse.from_dict(DONE)
+    assert TranscribeApi().transcribe_wait_until_done_blocking(status).status == "done"
+
+
+@pytest.mark.asyncio
+async def test_blocking_from_a_worker_thread():
+    status = StatusResponse.from_dict(DONE)
+    api = TranscribeApi()
+    result = await asyncio.to_thread(api.transcribe_wait_until_done_blocking, status)
+    assert result.status == "done"
+
+
+def async_methods():
+    return [
+        name
+        for name in dir(TranscribeApi)
+        if not name.startswith("_")
+        and not name.endswith("_blocking")
+        and name not in ("debug_captures", "clear_debug_captures")
+    ]
+
+
+def test_every_async_method_has_a_blocking_twin():
+    names = async_methods()
+    assert "transcribe_upload" in names
+    for name in names:
+        blocking = getattr(TranscribeApi, f"{name}_blocking")
+        assert inspect.signature(blocking) == inspect.signature(getattr(TranscribeApi, name))
+
+
+@pytest.mark.parametrize(
+    "call",
+    [
+        lambda api: api.transcribe_status_blocking("task-1"),
+        lambda api: api.task_exists_blocking("task-1"),
+        lambda api: api.get_keywords_blocking("task-1"),
+        lambda api: api.translate_text_blocking("你好", "en"),
+    ],
+)
+def test_blocking_methods_raise_directly(call):
+    # Without a token the call fails before any request is made.
+    with pytest.raises(InvalidInputError, match="no token given"):
+        call(TranscribeApi())
+
+
+def test_blocking_validation_matches_async():
+    api = TranscribeApi(token="token")
+    with pytest.raises(InvalidInputError):
+        api.transcribe_status_many_blocking([], timeout_seconds=-1.0)
+    with pytest.raises(InvalidInputError):
+        api.transcribe_export_blocking("task-1", "transcript", "docx-but-not-really")
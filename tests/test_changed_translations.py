@@ -0,0 +1,46 @@
+from dianyaapi import TranscribeTranslationResponse, changed_translations
+
+
+def translation(details):
+    return TranscribeTranslationResponse.from_dict(
+        {
+            "task_id": "task-1",
+            "task_type": "translate",
+            "status": "done",
+            "target_language": "en",
+            "details": details,
+        }
+    )
+
+
+def detail(start_time, text, translated, speaker=0):
+    return {
+        "start_time": start_time,
+        "end_time": start_time + 1.0,
+        "text": text,
+        "speaker": speaker,
+        "translations": {"en": translated},
+    }
+
+
+def test_only_new_and_edited_details_are_returned():
+    previous = translation([detail(0.0, "你好", "Hello"), detail(1.0, "再见", "Bye")])
+    current = translation(
+        [
+            detail(0.0, "你好", "Hello"),
+            detail(1.0, "再见", "Goodbye"),
+            detail(2.0, "谢谢", "Thanks"),
+        ]
+    )
+    changed = changed_translations(previous, current)
+    assert [d.translations["en"] for d in changed] == ["Goodbye", "Thanks"]
+
+
+def test_a_different_speaker_is_a_new_detail():
+    previous = translation([detail(0.0, "你好", "Hello", speaker=0)])
+    current = translation([detail(0.0, "你好", "Hello", speaker=1)])
+    assert [d.speaker for d in changed_translations(previous, current)] == [1]
+
+
+def test_missing_details_mean_nothing_changed():
+    assert changed_translations(translation(None), translation(None)) == []
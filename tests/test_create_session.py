@@ -0,0 +1,25 @@
+import logging
+
+import pytest
+
+from dianyaapi import DianyaError, InvalidInputError, TranscribeStream, set_log_level
+
+
+@pytest.mark.asyncio
+async def test_rejected_token_is_not_retried(caplog):
+    # Whether the request is refused for the token or never gets past name
+    # resolution, the failure is not transient and must not be retried.
+    set_log_level("info")
+    try:
+        with caplog.at_level(logging.INFO, logger="dianyaapi"):
+            with pytest.raises(DianyaError):
+                await TranscribeStream.create_session("speed", "invalid-token", max_attempts=5)
+    finally:
+        set_log_level("warning")
+    assert not [record for record in caplog.records if "retrying" in record.getMessage()]
+
+
+@pytest.mark.asyncio
+async def test_invalid_max_attempts_is_rejected():
+    with pytest.raises(InvalidInputError):
+        await TranscribeStream.create_session("speed", "token", max_attempts=0)
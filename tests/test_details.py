@@ -0,0 +1,25 @@
+from dianyaapi import StatusResponse
+
+STATUS = {
+    "status": "done",
+    "task_id": "task-1",
+    "details": [{"start_time": 0.0, "end_time": 1.0, "text": "你好", "speaker": 0}],
+    "keywords": [],
+    "callback_history": [],
+}
+
+
+def test_details_getter_returns_copies():
+    status = StatusResponse.from_dict(STATUS)
+    status.details[0].text = "lost"
+    assert status.details[0].text == "你好"
+
+
+def test_with_details_replaces_details_on_a_copy():
+    status = StatusResponse.from_dict(STATUS)
+    details = status.details
+    details[0].text = "fixed"
+    edited = status.with_details(details)
+    assert edited.details[0].text == "fixed"
+    assert status.details[0].text == "你好"
+    assert edited.task_id == status.task_id
@@ -0,0 +1,31 @@
+import pytest
+
+from dianyaapi import CallbackHistoryItem, UtterancePayload
+
+
+def utterance(**overrides) -> UtterancePayload:
+    fields = {"start_time": 0.0, "end_time": 1.0, "text": "你好", "speaker": 0}
+    return UtterancePayload.from_dict({**fields, **overrides})
+
+
+def test_utterances_compare_by_value():
+    assert utterance() == utterance()
+    assert utterance() != utterance(text="hello")
+    assert utterance(start_time=0.0) == utterance(start_time=-0.0)
+    nan = utterance(start_time=float("nan"))
+    assert nan != nan
+
+
+def test_utterances_are_not_hashable():
+    with pytest.raises(TypeError):
+        hash(utterance())
+
+
+def test_callback_history_items_hash_by_value():
+    item = {"timestamp": "2026-01-01T00:00:00Z", "status": "done", "code": 200}
+    first = CallbackHistoryItem.from_dict(item)
+    second = CallbackHistoryItem.from_dict(item)
+    assert first == second
+    assert len({first, second}) == 1
+    with pytest.raises(AttributeError):
+        first.status = "failed"
@@ -0,0 +1,81 @@
+import json
+from types import MappingProxyType
+
+import pytest
+
+from dianyaapi import (
+    InvalidInputError,
+    StatusResponse,
+    TranscribeTranslationResponse,
+    UtterancePayload,
+)
+
+STATUS = {
+    "status": "done",
+    "overview_md": "# overview",
+    "details": [{"start_time": 0.0, "end_time": 1.5, "text": "你好", "speaker": 0}],
+    "task_id": "task-1",
+    "keywords": ["greeting"],
+    "callback_history": [{"timestamp": "2026-01-01T00:00:00Z", "status": "done", "code": 200}],
+}
+
+TRANSLATION = {
+    "task_id": "task-1",
+    "task_type": "translate",
+    "status": "done",
+    "target_language": "en",
+    "details": [
+        {
+            "start_time": 0.0,
+            "end_time": 1.5,
+            "text": "你好",
+            "speaker": 0,
+            "translations": {"en": "Hello"},
+        }
+    ],
+}
+
+
+@pytest.mark.parametrize(
+    "cls, data",
+    [
+        (StatusResponse, STATUS),
+        (TranscribeTranslationResponse, TRANSLATION),
+        (UtterancePayload, STATUS["details"][0]),
+    ],
+)
+def test_json_round_trip(cls, data):
+    original = cls.from_dict(data)
+    restored = cls.from_dict(json.loads(json.dumps(original.to_dict())))
+    assert restored.to_dict() == original.to_dict()
+
+
+def test_optional_fields_may_be_absent_and_unknown_keys_are_ignored():
+    status = StatusResponse.from_dict({**STATUS, "unknown": 1})
+    assert status.summary_md is None
+    assert status.details[0].text == "你好"
+
+
+def test_missing_field_is_named():
+    detail = {key: value for key, value in STATUS["details"][0].items() if key != "text"}
+    with pytest.raises(InvalidInputError, match="text"):
+        StatusResponse.from_dict({**STATUS, "details": [detail]})
+
+
+def test_mistyped_field_is_named():
+    with pytest.raises(InvalidInputError, match="keywords"):
+        StatusResponse.from_dict({**STATUS, "keywords": "greeting"})
+
+
+def test_any_mapping_is_accepted():
+    translation = {**TRANSLATION["details"][0], "translations": MappingProxyType({"en": "Hello"})}
+    response = TranscribeTranslationResponse.from_dict(
+        MappingProxyType({**TRANSLATION, "details": [MappingProxyType(translation)]})
+    )
+    assert response.details[0].translations == {"en": "Hello"}
+
+
+def test_a_list_is_not_a_mapping():
+    detail = {**TRANSLATION["details"][0], "translations": [("en", "Hello")]}
+    with pytest.raises(InvalidInputError, match="translations"):
+        TranscribeTranslationResponse.from_dict({**TRANSLATION, "details": [detail]})
@@ -0,0 +1,13 @@
+import pytest
+
+from dianyaapi import InvalidInputError, TranscribeStream
+
+
+@pytest.mark.parametrize("idle_timeout", [0.0, -1.0])
+def test_idle_timeout_must_be_positive(idle_timeout):
+    with pytest.raises(InvalidInputError, match="idle_timeout"):
+        TranscribeStream("session-1", idle_timeout=idle_timeout)
+
+
+def test_positive_idle_timeout_is_accepted():
+    TranscribeStream("session-1", idle_timeout=0.5)
@@ -0,0 +1,28 @@
+from dianyaapi import StatusResponse
+
+STATUS = {
+    "status": "done",
+    "details": [],
+    "keywords": ["Budget", "预算"],
+    "callback_history": [],
+}
+
+
+def test_present_keywords_match_case_insensitively():
+    status = StatusResponse.from_dict(STATUS)
+    assert status.has_keyword("budget")
+    assert status.has_keyword("BUDGET")
+    assert status.has_keyword("预算")
+
+
+def test_absent_keywords_do_not_match():
+    status = StatusResponse.from_dict(STATUS)
+    assert not status.has_keyword("deadline")
+    assert not status.has_keyword("budg")
+    assert not StatusResponse.from_dict({**STATUS, "keywords": []}).has_keyword("budget")
+
+
+def test_case_sensitive_lookup():
+    status = StatusResponse.from_dict(STATUS)
+    assert status.has_keyword("Budget", case_sensitive=True)
+    assert not status.has_keyword("budget", case_sensitive=True)
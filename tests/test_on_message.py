@@ -0,0 +1,30 @@
+import asyncio
+
+import pytest
+
+from dianyaapi import TranscribeStream
+
+
+@pytest.mark.asyncio
+async def test_dispatch_runs_without_being_awaited():
+    stream = TranscribeStream("session-that-is-never-started")
+    dispatch = stream.on_message(lambda message: None)
+    await asyncio.sleep(0.05)
+    await stream.aclose()
+    assert await asyncio.wait_for(dispatch, timeout=5) == 0
+
+
+@pytest.mark.asyncio
+async def test_registering_again_replaces_the_callback():
+    stream = TranscribeStream("session-that-is-never-started")
+    first = stream.on_message(lambda message: None)
+    second = stream.on_message(lambda message: None)
+    assert await asyncio.wait_for(first, timeout=5) == 0
+    await stream.aclose()
+    assert await asyncio.wait_for(second, timeout=5) == 0
+
+
+def test_rejects_non_callable():
+    stream = TranscribeStream("session-that-is-never-started")
+    with pytest.raises(Exception, match="callable"):
+        stream.on_message("not callable")
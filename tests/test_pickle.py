@@ -0,0 +1,47 @@
+import copy
+import pickle
+
+from dianyaapi import StatusResponse, UtterancePayload
+
+STATUS = {
+    "status": "done",
+    "overview_md": "# overview",
+    "summary_md": None,
+    "details": [
+        {"start_time": 0.0, "end_time": 1.5, "text": "你好", "speaker": 0},
+        {"start_time": 1.5, "end_time": 3.25, "text": "hello", "speaker": 3},
+    ],
+    "message": None,
+    "usage_id": "usage-1",
+    "task_id": "task-1",
+    "keywords": ["greeting"],
+    "callback_history": [{"timestamp": "2026-01-01T00:00:00Z", "status": "done", "code": 200}],
+    "task_type": "normal_quality",
+    "speaker_map": {3: 1, 0: 0},
+}
+
+
+def test_status_response_round_trip():
+    status = StatusResponse.from_dict(STATUS)
+    restored = pickle.loads(pickle.dumps(status))
+    assert isinstance(restored, StatusResponse)
+    assert restored.to_dict() == status.to_dict()
+    assert restored.speaker_map == {3: 1, 0: 0}
+    assert all(isinstance(u, UtterancePayload) for u in restored.details)
+    assert [u.text for u in restored.details] == ["你好", "hello"]
+
+
+def test_status_response_without_speaker_map():
+    status = StatusResponse.from_dict({**STATUS, "speaker_map": None})
+    assert pickle.loads(pickle.dumps(status)).speaker_map is None
+
+
+def test_copy_and_deepcopy():
+    status = StatusResponse.from_dict(STATUS)
+    assert copy.copy(status).to_dict() == status.to_dict()
+    assert copy.deepcopy(status).to_dict() == status.to_dict()
+
+
+def test_utterance_round_trip():
+    utterance = UtterancePayload.from_dict(STATUS["details"][1])
+    assert pickle.loads(pickle.dumps(utterance)).to_dict() == utterance.to_dict()
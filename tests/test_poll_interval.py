@@ -0,0 +1,22 @@
+import pytest
+
+from dianyaapi import InvalidInputError, TranscribeApi
+
+
+@pytest.mark.parametrize("interval", [0.0, -2.0])
+def test_translation_poll_interval_must_be_positive(interval):
+    api = TranscribeApi(token="not-a-real-token")
+    with pytest.raises(InvalidInputError, match="poll_interval_seconds"):
+        api.poll_translation_until_done("task-1", "en", poll_interval_seconds=interval)
+
+
+def test_translated_summary_poll_interval_must_be_positive():
+    api = TranscribeApi(token="not-a-real-token")
+    with pytest.raises(InvalidInputError, match="poll_interval_seconds"):
+        api.translate_with_summary("task-1", "en", poll_interval_seconds=0.0)
+
+
+def test_chunked_summary_poll_interval_must_be_positive():
+    api = TranscribeApi(token="not-a-real-token")
+    with pytest.raises(InvalidInputError, match="poll_interval_seconds"):
+        api.transcribe_create_summary_chunked([], combine=True, poll_interval_seconds=0.0)
@@ -0,0 +1,31 @@
+import pytest
+
+from dianyaapi import InvalidInputError, recommend_model
+
+
+@pytest.mark.parametrize(
+    "seconds, model, short_asr",
+    [
+        (5.0, "speed", True),
+        (60.0, "speed", True),
+        (61.0, "speed", False),
+        (600.0, "quality", False),
+        (3600.0, "quality", False),
+    ],
+)
+def test_recommendation_by_duration(seconds, model, short_asr):
+    recommendation = recommend_model(seconds)
+    assert recommendation.model == model
+    assert recommendation.short_asr is short_asr
+    assert recommendation.transcribe_only is short_asr
+    assert recommendation.duration == seconds
+
+
+def test_thresholds_are_configurable():
+    recommendation = recommend_model(30.0, short_asr_max_seconds=10.0, quality_min_seconds=20.0)
+    assert recommendation.model == "quality"
+
+
+def test_inverted_thresholds_are_rejected():
+    with pytest.raises(InvalidInputError):
+        recommend_model(30.0, short_asr_max_seconds=100.0, quality_min_seconds=10.0)
@@ -0,0 +1,30 @@
+from dianyaapi import StatusResponse, to_report_markdown
+
+STATUS = {
+    "status": "done",
+    "overview_md": "两位发言人讨论了预算。",
+    "summary_md": "- 预算下调",
+    "details": [
+        {"start_time": 0.0, "end_time": 1.0, "text": "预算要下调。", "speaker": 0},
+        {"start_time": 1.0, "end_time": 2.0, "text": "同意。", "speaker": 1},
+    ],
+    "keywords": ["预算"],
+    "callback_history": [],
+}
+
+
+def test_two_speaker_report():
+    status = StatusResponse.from_dict(STATUS)
+    assert to_report_markdown(status, title="周会") == (
+        "# 周会\n\n"
+        "## Overview\n\n两位发言人讨论了预算。\n\n"
+        "## Summary\n\n- 预算下调\n\n"
+        "## Keywords\n\n- 预算\n"
+    )
+
+
+def test_empty_transcript_report():
+    status = StatusResponse.from_dict(
+        {"status": "done", "details": [], "keywords": [], "callback_history": []}
+    )
+    assert to_report_markdown(status) == ""
@@ -0,0 +1,54 @@
+from dianyaapi import StatusResponse, TranscribeTranslationResponse, UtterancePayload
+
+STATUS = {
+    "status": "done",
+    "details": [{"start_time": 0.0, "end_time": 1.0, "text": "你好", "speaker": 0}],
+    "keywords": [],
+    "callback_history": [],
+    "speaker_map": {1: 0},
+}
+
+
+def test_status_repr_lists_every_field():
+    text = repr(StatusResponse.from_dict(STATUS))
+    assert text.startswith("StatusResponse(status='done', ")
+    assert "details=[1 item]" in text
+    assert text.endswith("task_type=None, speaker_map={1 entry})")
+
+
+def test_repr_follows_to_dict_order():
+    utterance = UtterancePayload.from_dict(STATUS["details"][0])
+    assert list(utterance.to_dict()) == ["start_time", "end_time", "text", "speaker"]
+    assert repr(utterance) == (
+        "UtterancePayload(start_time=0.0, end_time=1.0, text='你好', speaker=0)"
+    )
+
+
+def test_status_str_is_the_transcript():
+    status = StatusResponse.from_dict(
+        {**STATUS, "details": STATUS["details"] + [
+            {"start_time": 1.0, "end_time": 2.5, "text": "再见", "speaker": 1},
+        ]}
+    )
+    assert str(status) == "[0.00s-1.00s] speaker 0: 你好\n[1.00s-2.50s] speaker 1: 再见"
+
+
+def test_status_str_without_details_is_the_repr():
+    status = StatusResponse.from_dict({**STATUS, "details": []})
+    assert str(status) == repr(status)
+
+
+def test_translation_str_prefers_the_target_language():
+    response = TranscribeTranslationResponse.from_dict({
+        "task_id": "task-1",
+        "task_type": "transcribe",
+        "status": "done",
+        "target_language": "en",
+        "details": [
+            {"start_time": 0.0, "end_time": 1.0, "text": "你好", "speaker": 0,
+             "translations": {"en": "Hello"}},
+            {"start_time": 1.0, "end_time": 2.0, "text": "再见", "speaker": 0,
+             "translations": {}},
+        ],
+    })
+    assert str(response) == "[0.00s-1.00s] speaker 0: Hello\n[1.00s-2.00s] speaker 0: 再见"
@@ -0,0 +1,44 @@
+import pytest
+
+from dianyaapi import InvalidInputError, StreamClosedError, TranscribeStream
+
+
+async def chunks(*items):
+    for item in items:
+        yield item
+
+
+def buffered_stream() -> TranscribeStream:
+    # Frames sent before start() are queued, so no connection is needed.
+    return TranscribeStream("session-that-is-never-started", buffer_until_ready=True)
+
+
+@pytest.mark.asyncio
+async def test_every_chunk_is_sent():
+    stream = buffered_stream()
+    sent = await stream.send_from_async_iter(chunks(b"\x00\x01", bytearray(b"\x02"), b"\x03"))
+    assert sent == 3
+    await stream.aclose()
+
+
+@pytest.mark.asyncio
+async def test_empty_source_sends_nothing():
+    stream = buffered_stream()
+    assert await stream.send_from_async_iter(chunks(), pace_seconds=0.01) == 0
+    await stream.aclose()
+
+
+@pytest.mark.asyncio
+async def test_non_bytes_chunk_names_its_index():
+    stream = buffered_stream()
+    with pytest.raises(InvalidInputError, match="chunk 1"):
+        await stream.send_from_async_iter(chunks(b"\x00", "text"))
+    await stream.aclose()
+
+
+@pytest.mark.asyncio
+async def test_closed_stream_rejects_chunks():
+    stream = buffered_stream()
+    await stream.aclose()
+    with pytest.raises(StreamClosedError):
+        await stream.send_from_async_iter(chunks(b"\x00"))
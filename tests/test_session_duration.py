@@ -0,0 +1,22 @@
+import warnings
+
+import pytest
+
+from dianyaapi import InvalidInputError, check_session_duration
+
+
+def test_audio_within_max_time_passes_silently():
+    with warnings.catch_warnings():
+        warnings.simplefilter("error")
+        assert check_session_duration(30.0, 60.0) == 30.0
+        assert check_session_duration(60.0, 60.0) == 60.0
+
+
+def test_audio_past_max_time_warns():
+    with pytest.warns(UserWarning, match="would be dropped"):
+        assert check_session_duration(90.0, 60.0) == 90.0
+
+
+def test_strict_raises_instead_of_warning():
+    with pytest.raises(InvalidInputError, match="would be dropped"):
+        check_session_duration(90.0, 60.0, strict=True)
@@ -0,0 +1,44 @@
+import pytest
+
+from dianyaapi import StatusResponse, UtterancePayload
+
+DETAILS = [
+    {"start_time": 0.0, "end_time": 1.0, "text": "你好", "speaker": 0},
+    {"start_time": 1.0, "end_time": 2.0, "text": "hello", "speaker": 1},
+]
+STATUS = {
+    "status": "done",
+    "details": DETAILS,
+    "keywords": ["greeting"],
+    "callback_history": [],
+}
+
+
+def test_len_and_indexing():
+    status = StatusResponse.from_dict(STATUS)
+    assert len(status) == 2
+    assert status[0].text == "你好"
+    assert status[-1].text == "hello"
+    with pytest.raises(IndexError):
+        status[2]
+    with pytest.raises(IndexError):
+        status[-3]
+
+
+def test_iteration_matches_details():
+    status = StatusResponse.from_dict(STATUS)
+    assert [u.text for u in status] == [u.text for u in status.details]
+
+
+def test_in_checks_utterances_not_keywords():
+    status = StatusResponse.from_dict(STATUS)
+    assert UtterancePayload.from_dict(DETAILS[1]) in status
+    assert UtterancePayload.from_dict({**DETAILS[1], "speaker": 2}) not in status
+    assert "greeting" not in status
+    assert status.has_keyword("Greeting")
+
+
+def test_empty_response_is_truthy():
+    status = StatusResponse.from_dict({**STATUS, "details": []})
+    assert len(status) == 0
+    assert status
@@ -0,0 +1,58 @@
+import pytest
+
+from dianyaapi import DianyaError, StreamClosedError, TranscribeStream
+
+
+async def shut_down_stream() -> TranscribeStream:
+    stream = TranscribeStream("session-that-is-never-started")
+    await stream.shutdown()
+    return stream
+
+
+@pytest.mark.asyncio
+async def test_anext_after_shutdown():
+    stream = await shut_down_stream()
+    with pytest.raises(StreamClosedError):
+        await stream.__anext__()
+
+
+@pytest.mark.asyncio
+async def test_async_for_after_shutdown():
+    stream = await shut_down_stream()
+    with pytest.raises(StreamClosedError):
+        async for _ in stream:
+            pass
+
+
+@pytest.mark.asyncio
+async def test_on_message_after_shutdown():
+    stream = await shut_down_stream()
+    with pytest.raises(StreamClosedError):
+        await stream.on_message(lambda message: None)
+
+
+@pytest.mark.asyncio
+async def test_finish_and_collect_after_shutdown():
+    stream = await shut_down_stream()
+    with pytest.raises(StreamClosedError):
+        await stream.finish_and_collect(timeout=1.0)
+
+
+@pytest.mark.asyncio
+async def test_send_after_aclose_is_not_buffered():
+    stream = TranscribeStream("session-that-is-never-started", buffer_until_ready=True)
+    await stream.aclose()
+    with pytest.raises(StreamClosedError):
+        await stream.send_bytes(b"\x00\x00")
+    with pytest.raises(StreamClosedError):
+        await stream.send_text("{}")
+
+
+@pytest.mark.asyncio
+async def test_failed_start_with_timeout_raises_the_start_error():
+    # The session does not exist, so the handshake fails well before the
+    # timeout, whether or not the network is reachable.
+    stream = TranscribeStream("session-that-is-never-started")
+    with pytest.raises(DianyaError) as raised:
+        await stream.start(timeout=30.0)
+    assert "did not complete within" not in str(raised.value)
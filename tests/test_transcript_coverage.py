@@ -0,0 +1,34 @@
+import pytest
+
+from dianyaapi import InvalidInputError, transcript_coverage
+
+DETAILS = [
+    {"start_time": 0.0, "end_time": 4.0, "text": "你好", "speaker": 0},
+    {"start_time": 6.0, "end_time": 9.0, "text": "再见", "speaker": 1},
+]
+
+
+def test_coverage_runs_to_the_last_utterance():
+    report = transcript_coverage(DETAILS, 10.0)
+    assert report.covered_seconds == 9.0
+    assert report.audio_seconds == 10.0
+    assert report.fraction == pytest.approx(0.9)
+    assert not report.likely_truncated
+
+
+def test_short_coverage_is_flagged():
+    report = transcript_coverage(DETAILS, 20.0)
+    assert report.fraction == pytest.approx(0.45)
+    assert report.likely_truncated
+    assert not transcript_coverage(DETAILS, 20.0, threshold=0.4).likely_truncated
+
+
+def test_empty_transcript_covers_nothing():
+    report = transcript_coverage([], 10.0)
+    assert report.covered_seconds == 0.0
+    assert report.likely_truncated
+
+
+def test_threshold_outside_unit_range_is_rejected():
+    with pytest.raises(InvalidInputError):
+        transcript_coverage(DETAILS, 10.0, threshold=1.5)
@@ -0,0 +1,50 @@
+import pytest
+
+from dianyaapi import InvalidInputError, StatusResponse, TaskFailedError, TranscribeApi
+
+
+def status(value: str) -> StatusResponse:
+    return StatusResponse.from_dict(
+        {
+            "status": value,
+            "task_id": "task-1",
+            "details": [],
+            "keywords": [],
+            "callback_history": [],
+        }
+    )
+
+
+@pytest.mark.asyncio
+async def test_done_status_returns_without_a_request():
+    # No token: any request would fail before reaching the network.
+    done = await TranscribeApi().transcribe_wait_until_done(status("done"))
+    assert done.status == "done"
+    assert done.task_id == "task-1"
+
+
+@pytest.mark.asyncio
+async def test_failed_status_raises_without_a_request():
+    with pytest.raises(TaskFailedError, match="task-1"):
+        await TranscribeApi().transcribe_wait_until_done(status("failed"))
+
+
+def test_blocking_done_status():
+    done = TranscribeApi().transcribe_wait_until_done_blocking(status("success"))
+    assert done.status == "success"
+
+
+@pytest.mark.asyncio
+async def test_times_out():
+    api = TranscribeApi(token="not-a-real-token")
+    with pytest.raises(TimeoutError):
+        await api.transcribe_wait_until_done(
+            status("processing"), poll_interval_seconds=0.01, timeout_seconds=0.0
+        )
+
+
+@pytest.mark.parametrize("interval", [0.0, -1.0])
+def test_poll_interval_must_be_positive(interval):
+    api = TranscribeApi(token="not-a-real-token")
+    with pytest.raises(InvalidInputError, match="poll_interval_seconds"):
+        api.transcribe_wait_until_done(status("processing"), poll_interval_seconds=interval)